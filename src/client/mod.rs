@@ -3,13 +3,22 @@ use crate::cluster::service::ClusterService;
 use crate::config::ClientConfig;
 use crate::connection::manager::ConnectionManager;
 use crate::connection::registry::ConnectionRegistry;
+use crate::cp::cp_subsystem::CpSubsystem;
 use crate::invocation::service::InvocationService;
 use crate::lifecycle_service::LifecycleService;
 use crate::listener::service::ListenerService;
 use crate::partition_service::PartitionService;
+use crate::proxy::lock_reference_id_generator::LockReferenceIdGenerator;
 use crate::proxy::manager::ProxyManager;
+use crate::proxy::list_proxy::ListProxy;
 use crate::proxy::map_proxy::MapProxy;
 use crate::proxy::multimap_proxy::MultiMapProxy;
+use crate::proxy::queue_proxy::QueueProxy;
+use crate::proxy::replicated_map_proxy::ReplicatedMapProxy;
+use crate::proxy::ringbuffer_proxy::RingbufferProxy;
+use crate::proxy::set_proxy::SetProxy;
+use crate::proxy::topic::reliable_topic_proxy::ReliableTopicProxy;
+use crate::proxy::topic::topic_proxy::TopicProxy;
 use crate::serialization::schema_service::SchemaService;
 use crate::serialization::serializable::Serializable;
 use crate::serialization::service::SerializationServiceV1;
@@ -33,6 +42,7 @@ pub struct HazelcastClient {
     schema_service: Arc<SchemaService>,
     proxy_manager: Arc<ProxyManager>,
     lifecycle_service: Arc<LifecycleService>,
+    cp_subsystem: Arc<CpSubsystem>,
 }
 
 impl HazelcastClient {
@@ -61,6 +71,16 @@ impl HazelcastClient {
         self.connection_manager.connect_to_cluster().await;
     }
 
+    /// Closes the CP sessions of the client, which releases the locks it
+    /// holds. The connections to the members are not closed.
+    pub async fn shutdown(&mut self) {
+        if !self.active {
+            return;
+        }
+        self.active = false;
+        self.lifecycle_service.shutdown().await;
+    }
+
     pub async fn get_map<K, V>(&self, name: String) -> MapProxy<K, V>
     where
         K: Clone + Send + Sync + Serializable + 'static,
@@ -76,6 +96,60 @@ impl HazelcastClient {
     {
         self.proxy_manager.get_or_create_proxy(name, true).await
     }
+
+    pub async fn get_replicated_map<K, V>(&self, name: String) -> ReplicatedMapProxy<K, V>
+    where
+        K: Clone + Send + Sync + Serializable + 'static,
+        V: Clone + Send + Sync + Serializable + 'static,
+    {
+        self.proxy_manager.get_or_create_proxy(name, true).await
+    }
+
+    pub async fn get_queue<T>(&self, name: String) -> QueueProxy<T>
+    where
+        T: Clone + Send + Sync + Serializable + 'static,
+    {
+        self.proxy_manager.get_or_create_proxy(name, true).await
+    }
+
+    pub async fn get_list<T>(&self, name: String) -> ListProxy<T>
+    where
+        T: Clone + Send + Sync + Serializable + 'static,
+    {
+        self.proxy_manager.get_or_create_proxy(name, true).await
+    }
+
+    pub async fn get_set<T>(&self, name: String) -> SetProxy<T>
+    where
+        T: Clone + Send + Sync + Serializable + 'static,
+    {
+        self.proxy_manager.get_or_create_proxy(name, true).await
+    }
+
+    pub async fn get_topic<T>(&self, name: String) -> TopicProxy<T>
+    where
+        T: Clone + Send + Sync + Serializable + 'static,
+    {
+        self.proxy_manager.get_or_create_proxy(name, true).await
+    }
+
+    pub async fn get_reliable_topic<T>(&self, name: String) -> ReliableTopicProxy<T>
+    where
+        T: Clone + Send + Sync + Serializable + 'static,
+    {
+        self.proxy_manager.get_or_create_proxy(name, true).await
+    }
+
+    pub async fn get_ringbuffer<T>(&self, name: String) -> RingbufferProxy<T>
+    where
+        T: Clone + Send + Sync + Serializable + 'static,
+    {
+        self.proxy_manager.get_or_create_proxy(name, true).await
+    }
+
+    pub async fn cp_subsystem(&self) -> Arc<CpSubsystem> {
+        self.cp_subsystem.clone()
+    }
 }
 
 #[async_trait::async_trait]
@@ -133,8 +207,12 @@ impl AssistedInstantiable<HazelcastClientInstantiationData> for HazelcastClient
             serialization_service.clone(),
             listener_service.clone(),
             cluster_service.clone(),
+            Arc::new(LockReferenceIdGenerator::new()),
+            config.clone(),
         ));
 
+        let cp_subsystem = Arc::new(CpSubsystem::new(proxy_manager.clone(), lifecycle_service.clone()).await);
+
         let client = HazelcastClient {
             proxy_manager,
             connection_manager,
@@ -146,6 +224,7 @@ impl AssistedInstantiable<HazelcastClientInstantiationData> for HazelcastClient
             schema_service,
             serialization_service,
             lifecycle_service,
+            cp_subsystem,
         };
         client.init().await;
         client
@@ -0,0 +1,55 @@
+use crate::codec_builtin::string_codec::StringCodec;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::codec::custom::raft_group_id_codec::RaftGroupIdCodec;
+
+use std::mem::MaybeUninit;
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::util::bits_util::BitsUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+
+
+
+pub struct AtomicLongAddAndGetCodec;
+
+impl AtomicLongAddAndGetCodec {
+
+    // hex: 0x090300
+    const REQUEST_MESSAGE_TYPE: i32 = 590592;
+    // hex: 0x090301
+    // RESPONSE_MESSAGE_TYPE = 590593
+
+    const REQUEST_DELTA_OFFSET: usize = ClientMessage::PARTITION_ID_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
+    const REQUEST_INITIAL_FRAME_SIZE: usize = Self::REQUEST_DELTA_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const RESPONSE_RESPONSE_OFFSET: usize = ClientMessage::RESPONSE_BACKUP_ACKS_OFFSET as usize + BitsUtil::BYTE_SIZE_IN_BYTES as usize;
+
+    pub fn encode_request<'a>(group_id: &'a RaftGroupId, name: &'a String, delta: &'a i64) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let mut client_message = ClientMessage::create_for_encode().await;
+            client_message.retryable = false;
+
+            let initial_frame = Frame::create_initial_frame(Self::REQUEST_INITIAL_FRAME_SIZE, None);
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::REQUEST_DELTA_OFFSET, delta).await;
+            client_message.add_frame(initial_frame).await;
+            client_message.set_message_type(Self::REQUEST_MESSAGE_TYPE).await;
+            client_message.set_partition_id(-1).await;
+
+            RaftGroupIdCodec::encode(&mut client_message, group_id).await;
+            StringCodec::encode(&mut client_message, name).await;
+
+            client_message
+        })
+    }
+
+
+    pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=i64> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let initial_frame = client_message.next_frame().await.unwrap();
+
+            let x = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_RESPONSE_OFFSET).await; x
+        })
+    }
+
+
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::codec_builtin::string_codec::StringCodec;
+use crate::serialization::heap_data::HeapData;
+use crate::codec_builtin::data_codec::DataCodec;
+use crate::codec_builtin::codec_util::CodecUtil;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::codec::custom::raft_group_id_codec::RaftGroupIdCodec;
+
+use std::mem::MaybeUninit;
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::util::bits_util::BitsUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+
+
+
+pub struct AtomicRefApplyCodec;
+
+impl AtomicRefApplyCodec {
+
+    // hex: 0x0A0100
+    const REQUEST_MESSAGE_TYPE: i32 = 655616;
+    // hex: 0x0A0101
+    // RESPONSE_MESSAGE_TYPE = 655617
+
+    const REQUEST_RETURN_VALUE_TYPE_OFFSET: usize = ClientMessage::PARTITION_ID_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
+    const REQUEST_ALTER_OFFSET: usize = Self::REQUEST_RETURN_VALUE_TYPE_OFFSET + BitsUtil::INT_SIZE_IN_BYTES as usize;
+    const REQUEST_INITIAL_FRAME_SIZE: usize = Self::REQUEST_ALTER_OFFSET + BitsUtil::BOOLEAN_SIZE_IN_BYTES as usize;
+
+    pub fn encode_request<'a>(group_id: &'a RaftGroupId, name: &'a String, function: &'a HeapData, return_value_type: &'a i32, alter: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let mut client_message = ClientMessage::create_for_encode().await;
+            client_message.retryable = false;
+
+            let initial_frame = Frame::create_initial_frame(Self::REQUEST_INITIAL_FRAME_SIZE, None);
+            FixSizedTypesCodec::encode_int(&mut *initial_frame.content.lock().await, Self::REQUEST_RETURN_VALUE_TYPE_OFFSET, return_value_type).await;
+            FixSizedTypesCodec::encode_boolean(&mut *initial_frame.content.lock().await, Self::REQUEST_ALTER_OFFSET, alter).await;
+            client_message.add_frame(initial_frame).await;
+            client_message.set_message_type(Self::REQUEST_MESSAGE_TYPE).await;
+            client_message.set_partition_id(-1).await;
+
+            RaftGroupIdCodec::encode(&mut client_message, group_id).await;
+            StringCodec::encode(&mut client_message, name).await;
+            DataCodec::encode(&mut client_message, function).await;
+
+            client_message
+        })
+    }
+
+
+    pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Option<HeapData>> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            // empty initial frame
+            client_message.next_frame().await.unwrap();
+
+            CodecUtil::decode_nullable(client_message, DataCodec::decode).await
+        })
+    }
+
+
+}
\ No newline at end of file
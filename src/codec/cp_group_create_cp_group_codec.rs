@@ -0,0 +1,51 @@
+use crate::codec_builtin::string_codec::StringCodec;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::codec::custom::raft_group_id_codec::RaftGroupIdCodec;
+
+use std::mem::MaybeUninit;
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::util::bits_util::BitsUtil;
+use core::pin::Pin;
+use std::future::Future;
+
+
+
+pub struct CpGroupCreateCpGroupCodec;
+
+impl CpGroupCreateCpGroupCodec {
+
+    // hex: 0x1E0100
+    const REQUEST_MESSAGE_TYPE: i32 = 1966336;
+    // hex: 0x1E0101
+    // RESPONSE_MESSAGE_TYPE = 1966337
+
+    const REQUEST_INITIAL_FRAME_SIZE: usize = ClientMessage::PARTITION_ID_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
+
+    pub fn encode_request<'a>(proxy_name: &'a String) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let mut client_message = ClientMessage::create_for_encode().await;
+            client_message.retryable = true;
+
+            let initial_frame = Frame::create_initial_frame(Self::REQUEST_INITIAL_FRAME_SIZE, None);
+            client_message.add_frame(initial_frame).await;
+            client_message.set_message_type(Self::REQUEST_MESSAGE_TYPE).await;
+            client_message.set_partition_id(-1).await;
+
+            StringCodec::encode(&mut client_message, proxy_name).await;
+
+            client_message
+        })
+    }
+
+
+    pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=RaftGroupId> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            // empty initial frame
+            client_message.next_frame().await.unwrap();
+
+            RaftGroupIdCodec::decode(client_message).await
+        })
+    }
+
+
+}
\ No newline at end of file
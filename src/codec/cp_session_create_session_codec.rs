@@ -0,0 +1,68 @@
+use crate::codec_builtin::string_codec::StringCodec;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::codec::custom::raft_group_id_codec::RaftGroupIdCodec;
+
+use std::mem::MaybeUninit;
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::util::bits_util::BitsUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+
+
+
+/** @internal */
+#[derive(Default, Clone)]
+pub struct CpSessionCreateSessionResponseParams {
+    pub session_id: i64,
+    pub ttl_millis: i64,
+    pub heartbeat_millis: i64,
+}
+
+
+pub struct CpSessionCreateSessionCodec;
+
+impl CpSessionCreateSessionCodec {
+
+    // hex: 0x1F0100
+    const REQUEST_MESSAGE_TYPE: i32 = 2031872;
+    // hex: 0x1F0101
+    // RESPONSE_MESSAGE_TYPE = 2031873
+
+    const REQUEST_INITIAL_FRAME_SIZE: usize = ClientMessage::PARTITION_ID_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
+    const RESPONSE_SESSION_ID_OFFSET: usize = ClientMessage::RESPONSE_BACKUP_ACKS_OFFSET as usize + BitsUtil::BYTE_SIZE_IN_BYTES as usize;
+    const RESPONSE_TTL_MILLIS_OFFSET: usize = Self::RESPONSE_SESSION_ID_OFFSET as usize + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const RESPONSE_HEARTBEAT_MILLIS_OFFSET: usize = Self::RESPONSE_TTL_MILLIS_OFFSET as usize + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+
+    pub fn encode_request<'a>(group_id: &'a RaftGroupId, endpoint_name: &'a String) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let mut client_message = ClientMessage::create_for_encode().await;
+            client_message.retryable = false;
+
+            let initial_frame = Frame::create_initial_frame(Self::REQUEST_INITIAL_FRAME_SIZE, None);
+            client_message.add_frame(initial_frame).await;
+            client_message.set_message_type(Self::REQUEST_MESSAGE_TYPE).await;
+            client_message.set_partition_id(-1).await;
+
+            RaftGroupIdCodec::encode(&mut client_message, group_id).await;
+            StringCodec::encode(&mut client_message, endpoint_name).await;
+
+            client_message
+        })
+    }
+
+
+    pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=CpSessionCreateSessionResponseParams> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let initial_frame = client_message.next_frame().await.unwrap();
+            let mut response = CpSessionCreateSessionResponseParams::default();
+
+            response.session_id = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_SESSION_ID_OFFSET).await;
+            response.ttl_millis = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_TTL_MILLIS_OFFSET).await;
+            response.heartbeat_millis = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_HEARTBEAT_MILLIS_OFFSET).await;
+            response
+        })
+    }
+
+
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use crate::protocol::anchor_data_list_holder::AnchorDataListHolder;
+use crate::codec_builtin::list_integer_codec::ListIntegerCodec;
+use crate::codec_builtin::entry_list_codec::EntryListCodec;
+use crate::codec_builtin::data_codec::DataCodec;
+
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::codec_builtin::codec_util::CodecUtil;
+use core::pin::Pin;
+use std::future::Future;
+
+pub struct AnchorDataListHolderCodec;
+
+impl AnchorDataListHolderCodec {
+
+    pub fn encode<'a>(client_message: &'a mut ClientMessage, anchor_data_list_holder: &'a AnchorDataListHolder) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.add_frame(Frame::new_begin_frame()).await;
+
+            ListIntegerCodec::encode(client_message, &anchor_data_list_holder.anchor_page_list).await;
+            EntryListCodec::encode(client_message, &anchor_data_list_holder.anchor_data_list, DataCodec::encode, DataCodec::encode).await;
+
+            client_message.add_frame(Frame::new_end_frame()).await;
+        })
+    }
+
+    pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=AnchorDataListHolder> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.next_frame().await.unwrap();
+
+            let anchor_page_list = ListIntegerCodec::decode(client_message).await;
+            let anchor_data_list = EntryListCodec::decode(client_message, DataCodec::decode, DataCodec::decode).await;
+            CodecUtil::fast_forward_to_end_frame(client_message).await;
+
+            AnchorDataListHolder::new(anchor_page_list, anchor_data_list)
+        })
+    }
+}
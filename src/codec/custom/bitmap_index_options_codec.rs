@@ -0,0 +1,45 @@
+use crate::config::index::{BitmapIndexOptions, UniqueKeyTransformation};
+use crate::codec_builtin::string_codec::StringCodec;
+
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::codec_builtin::codec_util::CodecUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+use crate::util::bits_util::BitsUtil;
+
+pub struct BitmapIndexOptionsCodec;
+
+impl BitmapIndexOptionsCodec {
+    const UNIQUE_KEY_TRANSFORMATION_OFFSET: usize = 0;
+    const INITIAL_FRAME_SIZE: usize = Self::UNIQUE_KEY_TRANSFORMATION_OFFSET + BitsUtil::INT_SIZE_IN_BYTES as usize;
+
+    pub fn encode<'a>(client_message: &'a mut ClientMessage, bitmap_index_options: &'a BitmapIndexOptions) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.add_frame(Frame::new_begin_frame()).await;
+
+            let initial_frame = Frame::create_initial_frame(Self::INITIAL_FRAME_SIZE, Some(ClientMessage::DEFAULT_FLAGS));
+            FixSizedTypesCodec::encode_int(&mut *initial_frame.content.lock().await, Self::UNIQUE_KEY_TRANSFORMATION_OFFSET, &bitmap_index_options.unique_key_transformation.id()).await;
+            client_message.add_frame(initial_frame).await;
+
+            StringCodec::encode(client_message, &bitmap_index_options.unique_key).await;
+
+            client_message.add_frame(Frame::new_end_frame()).await;
+        })
+    }
+
+    pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=BitmapIndexOptions> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.next_frame().await.unwrap();
+            let initial_frame = client_message.next_frame().await.unwrap();
+            let unique_key_transformation = FixSizedTypesCodec::decode_int(&*initial_frame.content.lock().await, Self::UNIQUE_KEY_TRANSFORMATION_OFFSET).await;
+
+            let unique_key = StringCodec::decode(client_message).await;
+            CodecUtil::fast_forward_to_end_frame(client_message).await;
+
+            BitmapIndexOptions::default()
+              .unique_key(unique_key)
+              .unique_key_transformation(UniqueKeyTransformation::from_id(unique_key_transformation))
+        })
+    }
+}
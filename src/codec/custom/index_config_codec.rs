@@ -0,0 +1,54 @@
+use crate::config::index::{IndexType, InternalIndexConfig};
+use crate::codec::custom::bitmap_index_options_codec::BitmapIndexOptionsCodec;
+use crate::codec_builtin::string_codec::StringCodec;
+use crate::codec_builtin::list_multi_frame_codec::ListMultiFrameCodec;
+
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::codec_builtin::codec_util::CodecUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+use crate::util::bits_util::BitsUtil;
+
+pub struct IndexConfigCodec;
+
+impl IndexConfigCodec {
+    const TYPE_OFFSET: usize = 0;
+    const INITIAL_FRAME_SIZE: usize = Self::TYPE_OFFSET + BitsUtil::INT_SIZE_IN_BYTES as usize;
+
+    pub fn encode<'a>(client_message: &'a mut ClientMessage, index_config: &'a InternalIndexConfig) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.add_frame(Frame::new_begin_frame()).await;
+
+            let initial_frame = Frame::create_initial_frame(Self::INITIAL_FRAME_SIZE, Some(ClientMessage::DEFAULT_FLAGS));
+            FixSizedTypesCodec::encode_int(&mut *initial_frame.content.lock().await, Self::TYPE_OFFSET, &index_config.index_type.id()).await;
+            client_message.add_frame(initial_frame).await;
+
+            StringCodec::encode(client_message, &index_config.name).await;
+            ListMultiFrameCodec::encode(client_message, &index_config.attributes, |client_message, attribute| StringCodec::encode(client_message, attribute)).await;
+            CodecUtil::encode_nullable(client_message, &index_config.bitmap_index_options, BitmapIndexOptionsCodec::encode).await;
+
+            client_message.add_frame(Frame::new_end_frame()).await;
+        })
+    }
+
+    pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=InternalIndexConfig> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.next_frame().await.unwrap();
+            let initial_frame = client_message.next_frame().await.unwrap();
+            let index_type = FixSizedTypesCodec::decode_int(&*initial_frame.content.lock().await, Self::TYPE_OFFSET).await;
+
+            let name = CodecUtil::decode_nullable(client_message, StringCodec::decode).await;
+            let attributes = ListMultiFrameCodec::decode(client_message, StringCodec::decode).await;
+            let bitmap_index_options = CodecUtil::decode_nullable(client_message, BitmapIndexOptionsCodec::decode).await;
+            CodecUtil::fast_forward_to_end_frame(client_message).await;
+
+            InternalIndexConfig {
+                name: name.unwrap_or_default(),
+                index_type: IndexType::from_id(index_type),
+                attributes,
+                bitmap_index_options,
+            }
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::protocol::paging_predicate_holder::PagingPredicateHolder;
+use crate::codec::custom::anchor_data_list_holder_codec::AnchorDataListHolderCodec;
+use crate::codec_builtin::data_codec::DataCodec;
+
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::codec_builtin::codec_util::CodecUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+use crate::util::bits_util::BitsUtil;
+
+pub struct PagingPredicateHolderCodec;
+
+impl PagingPredicateHolderCodec {
+    const PAGE_SIZE_OFFSET: usize = 0;
+    const PAGE_OFFSET: usize = Self::PAGE_SIZE_OFFSET + BitsUtil::INT_SIZE_IN_BYTES as usize;
+    const ITERATION_TYPE_ID_OFFSET: usize = Self::PAGE_OFFSET + BitsUtil::INT_SIZE_IN_BYTES as usize;
+    const INITIAL_FRAME_SIZE: usize = Self::ITERATION_TYPE_ID_OFFSET + BitsUtil::BYTE_SIZE_IN_BYTES as usize;
+
+    pub fn encode<'a>(client_message: &'a mut ClientMessage, paging_predicate_holder: &'a PagingPredicateHolder) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.add_frame(Frame::new_begin_frame()).await;
+
+            let initial_frame = Frame::create_initial_frame(Self::INITIAL_FRAME_SIZE, Some(ClientMessage::DEFAULT_FLAGS));
+            FixSizedTypesCodec::encode_int(&mut *initial_frame.content.lock().await, Self::PAGE_SIZE_OFFSET, &paging_predicate_holder.page_size).await;
+            FixSizedTypesCodec::encode_int(&mut *initial_frame.content.lock().await, Self::PAGE_OFFSET, &paging_predicate_holder.page).await;
+            FixSizedTypesCodec::encode_byte(&mut *initial_frame.content.lock().await, Self::ITERATION_TYPE_ID_OFFSET, &paging_predicate_holder.iteration_type_id).await;
+            client_message.add_frame(initial_frame).await;
+
+            AnchorDataListHolderCodec::encode(client_message, &paging_predicate_holder.anchor_data_list_holder).await;
+            CodecUtil::encode_nullable(client_message, &paging_predicate_holder.predicate_data, DataCodec::encode).await;
+            CodecUtil::encode_nullable(client_message, &paging_predicate_holder.comparator_data, DataCodec::encode).await;
+            CodecUtil::encode_nullable(client_message, &paging_predicate_holder.partition_key_data, DataCodec::encode).await;
+
+            client_message.add_frame(Frame::new_end_frame()).await;
+        })
+    }
+
+    pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=PagingPredicateHolder> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.next_frame().await.unwrap();
+            let initial_frame = client_message.next_frame().await.unwrap();
+            let page_size = FixSizedTypesCodec::decode_int(&*initial_frame.content.lock().await, Self::PAGE_SIZE_OFFSET).await;
+            let page = FixSizedTypesCodec::decode_int(&*initial_frame.content.lock().await, Self::PAGE_OFFSET).await;
+            let iteration_type_id = FixSizedTypesCodec::decode_byte(&*initial_frame.content.lock().await, Self::ITERATION_TYPE_ID_OFFSET).await;
+
+            let anchor_data_list_holder = AnchorDataListHolderCodec::decode(client_message).await;
+            let predicate_data = CodecUtil::decode_nullable(client_message, DataCodec::decode).await;
+            let comparator_data = CodecUtil::decode_nullable(client_message, DataCodec::decode).await;
+            let partition_key_data = CodecUtil::decode_nullable(client_message, DataCodec::decode).await;
+            CodecUtil::fast_forward_to_end_frame(client_message).await;
+
+            PagingPredicateHolder::new(anchor_data_list_holder, predicate_data, comparator_data, page_size, page, iteration_type_id, partition_key_data)
+        })
+    }
+}
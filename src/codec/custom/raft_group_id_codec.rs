@@ -0,0 +1,47 @@
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::codec_builtin::string_codec::StringCodec;
+
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::codec_builtin::codec_util::CodecUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+use crate::util::bits_util::BitsUtil;
+
+pub struct RaftGroupIdCodec;
+
+impl RaftGroupIdCodec {
+    const SEED_OFFSET: usize = 0;
+    const ID_OFFSET: usize = Self::SEED_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const INITIAL_FRAME_SIZE: usize = Self::ID_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+
+
+    pub fn encode<'a>(client_message: &'a mut ClientMessage, raft_group_id: &'a RaftGroupId) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.add_frame(Frame::new_begin_frame()).await;
+
+            let mut initial_frame = Frame::create_initial_frame(Self::INITIAL_FRAME_SIZE, Some(ClientMessage::DEFAULT_FLAGS));
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::SEED_OFFSET, &raft_group_id.seed).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::ID_OFFSET, &raft_group_id.id).await;
+            client_message.add_frame(initial_frame).await;
+
+            StringCodec::encode(client_message, &raft_group_id.name).await;
+
+            client_message.add_frame(Frame::new_end_frame()).await;
+        })
+    }
+
+    pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=RaftGroupId> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.next_frame().await.unwrap();
+            let mut initial_frame = client_message.next_frame().await.unwrap();
+            let seed = FixSizedTypesCodec::decode_long(&mut *initial_frame.content.lock().await, Self::SEED_OFFSET).await;
+            let id = FixSizedTypesCodec::decode_long(&mut *initial_frame.content.lock().await, Self::ID_OFFSET).await;
+
+            let name = StringCodec::decode(client_message).await;
+            CodecUtil::fast_forward_to_end_frame(client_message).await;
+
+            RaftGroupId::new(name, seed, id)
+        })
+    }
+}
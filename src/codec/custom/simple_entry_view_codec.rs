@@ -0,0 +1,86 @@
+use crate::core::entry_view::EntryView;
+use crate::serialization::heap_data::HeapData;
+use crate::codec_builtin::data_codec::DataCodec;
+
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::codec_builtin::codec_util::CodecUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+use crate::util::bits_util::BitsUtil;
+
+pub struct SimpleEntryViewCodec;
+
+impl SimpleEntryViewCodec {
+    const COST_OFFSET: usize = 0;
+    const CREATION_TIME_OFFSET: usize = Self::COST_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const EXPIRATION_TIME_OFFSET: usize = Self::CREATION_TIME_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const HITS_OFFSET: usize = Self::EXPIRATION_TIME_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const LAST_ACCESS_TIME_OFFSET: usize = Self::HITS_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const LAST_STORED_TIME_OFFSET: usize = Self::LAST_ACCESS_TIME_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const LAST_UPDATE_TIME_OFFSET: usize = Self::LAST_STORED_TIME_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const VERSION_OFFSET: usize = Self::LAST_UPDATE_TIME_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const TTL_OFFSET: usize = Self::VERSION_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const MAX_IDLE_OFFSET: usize = Self::TTL_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const INITIAL_FRAME_SIZE: usize = Self::MAX_IDLE_OFFSET + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+
+    pub fn encode<'a>(client_message: &'a mut ClientMessage, entry_view: &'a EntryView<HeapData, HeapData>) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.add_frame(Frame::new_begin_frame()).await;
+
+            let initial_frame = Frame::create_initial_frame(Self::INITIAL_FRAME_SIZE, Some(ClientMessage::DEFAULT_FLAGS));
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::COST_OFFSET, &entry_view.cost).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::CREATION_TIME_OFFSET, &entry_view.creation_time).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::EXPIRATION_TIME_OFFSET, &entry_view.expiration_time).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::HITS_OFFSET, &entry_view.hits).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::LAST_ACCESS_TIME_OFFSET, &entry_view.last_access_time).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::LAST_STORED_TIME_OFFSET, &entry_view.last_stored_time).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::LAST_UPDATE_TIME_OFFSET, &entry_view.last_update_time).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::VERSION_OFFSET, &entry_view.version).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::TTL_OFFSET, &entry_view.ttl).await;
+            FixSizedTypesCodec::encode_long(&mut *initial_frame.content.lock().await, Self::MAX_IDLE_OFFSET, &entry_view.max_idle).await;
+            client_message.add_frame(initial_frame).await;
+
+            DataCodec::encode(client_message, &entry_view.key).await;
+            DataCodec::encode(client_message, &entry_view.value).await;
+
+            client_message.add_frame(Frame::new_end_frame()).await;
+        })
+    }
+
+    pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=EntryView<HeapData, HeapData>> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            client_message.next_frame().await.unwrap();
+            let initial_frame = client_message.next_frame().await.unwrap();
+            let cost = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::COST_OFFSET).await;
+            let creation_time = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::CREATION_TIME_OFFSET).await;
+            let expiration_time = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::EXPIRATION_TIME_OFFSET).await;
+            let hits = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::HITS_OFFSET).await;
+            let last_access_time = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::LAST_ACCESS_TIME_OFFSET).await;
+            let last_stored_time = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::LAST_STORED_TIME_OFFSET).await;
+            let last_update_time = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::LAST_UPDATE_TIME_OFFSET).await;
+            let version = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::VERSION_OFFSET).await;
+            let ttl = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::TTL_OFFSET).await;
+            let max_idle = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::MAX_IDLE_OFFSET).await;
+
+            let key = DataCodec::decode(client_message).await;
+            let value = DataCodec::decode(client_message).await;
+            CodecUtil::fast_forward_to_end_frame(client_message).await;
+
+            EntryView {
+                key,
+                value,
+                cost,
+                creation_time,
+                expiration_time,
+                hits,
+                last_access_time,
+                last_stored_time,
+                last_update_time,
+                version,
+                ttl,
+                max_idle,
+            }
+        })
+    }
+}
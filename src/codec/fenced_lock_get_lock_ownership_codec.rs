@@ -0,0 +1,71 @@
+use crate::codec_builtin::string_codec::StringCodec;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::codec::custom::raft_group_id_codec::RaftGroupIdCodec;
+
+use std::mem::MaybeUninit;
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::util::bits_util::BitsUtil;
+use core::pin::Pin;
+use std::future::Future;
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+
+
+
+/** @internal */
+#[derive(Default, Clone)]
+pub struct FencedLockGetLockOwnershipResponseParams {
+    pub fence: i64,
+    pub lock_count: i32,
+    pub session_id: i64,
+    pub thread_id: i64,
+}
+
+
+pub struct FencedLockGetLockOwnershipCodec;
+
+impl FencedLockGetLockOwnershipCodec {
+
+    // hex: 0x070400
+    const REQUEST_MESSAGE_TYPE: i32 = 459776;
+    // hex: 0x070401
+    // RESPONSE_MESSAGE_TYPE = 459777
+
+    const REQUEST_INITIAL_FRAME_SIZE: usize = ClientMessage::PARTITION_ID_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
+    const RESPONSE_FENCE_OFFSET: usize = ClientMessage::RESPONSE_BACKUP_ACKS_OFFSET as usize + BitsUtil::BYTE_SIZE_IN_BYTES as usize;
+    const RESPONSE_LOCK_COUNT_OFFSET: usize = Self::RESPONSE_FENCE_OFFSET as usize + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+    const RESPONSE_SESSION_ID_OFFSET: usize = Self::RESPONSE_LOCK_COUNT_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
+    const RESPONSE_THREAD_ID_OFFSET: usize = Self::RESPONSE_SESSION_ID_OFFSET as usize + BitsUtil::LONG_SIZE_IN_BYTES as usize;
+
+    pub fn encode_request<'a>(group_id: &'a RaftGroupId, name: &'a String) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let mut client_message = ClientMessage::create_for_encode().await;
+            client_message.retryable = false;
+
+            let initial_frame = Frame::create_initial_frame(Self::REQUEST_INITIAL_FRAME_SIZE, None);
+            client_message.add_frame(initial_frame).await;
+            client_message.set_message_type(Self::REQUEST_MESSAGE_TYPE).await;
+            client_message.set_partition_id(-1).await;
+
+            RaftGroupIdCodec::encode(&mut client_message, group_id).await;
+            StringCodec::encode(&mut client_message, name).await;
+
+            client_message
+        })
+    }
+
+
+    pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=FencedLockGetLockOwnershipResponseParams> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let initial_frame = client_message.next_frame().await.unwrap();
+            let mut response = FencedLockGetLockOwnershipResponseParams::default();
+
+            response.fence = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_FENCE_OFFSET).await;
+            response.lock_count = FixSizedTypesCodec::decode_int(&*initial_frame.content.lock().await, Self::RESPONSE_LOCK_COUNT_OFFSET).await;
+            response.session_id = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_SESSION_ID_OFFSET).await;
+            response.thread_id = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_THREAD_ID_OFFSET).await;
+            response
+        })
+    }
+
+
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use crate::codec_builtin::string_codec::StringCodec;
-use crate::config::InternalIndexConfig;
-use crate::codec::custom::custom::IndexConfigCodec;
+use crate::config::index::InternalIndexConfig;
+use crate::codec::custom::index_config_codec::IndexConfigCodec;
 
 use std::mem::MaybeUninit;
 use crate::protocol::client_message::{ClientMessage, Frame};
@@ -5,7 +5,6 @@ use crate::codec_builtin::codec_util::CodecUtil;
 use crate::codec_builtin::list_multi_frame_codec::ListMultiFrameCodec;
 use crate::codec_builtin::long_array_codec::LongArrayCodec;
 
-use std::mem::MaybeUninit;
 use crate::protocol::client_message::{ClientMessage, Frame};
 use crate::util::bits_util::BitsUtil;
 use core::pin::Pin;
@@ -18,7 +17,7 @@ use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
 pub struct MapEventJournalReadResponseParams {
     pub read_count: i32,
     pub items: Vec<HeapData>,
-    pub item_seqs: Option<Long[]>,
+    pub item_seqs: Option<Vec<i64>>,
     pub next_seq: i64,
 }
 
@@ -39,7 +38,7 @@ impl MapEventJournalReadCodec {
     const RESPONSE_READ_COUNT_OFFSET: usize = ClientMessage::RESPONSE_BACKUP_ACKS_OFFSET as usize + BitsUtil::BYTE_SIZE_IN_BYTES as usize;
     const RESPONSE_NEXT_SEQ_OFFSET: usize = Self::RESPONSE_READ_COUNT_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
 
-    pub fn encode_request<'a>(name: &'a String, start_sequence: &'a i64, min_size: &'a i32, max_size: &'a i32, predicate: &'a Option<&'a HeapData>, projection: &'a Option<&'a HeapData>) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    pub fn encode_request<'a>(name: &'a String, start_sequence: &'a i64, min_size: &'a i32, max_size: &'a i32, predicate: &'a Option<HeapData>, projection: &'a Option<HeapData>) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
         Box::pin(async move {
             let mut client_message = ClientMessage::create_for_encode().await;
             client_message.retryable = true;
@@ -63,8 +62,7 @@ impl MapEventJournalReadCodec {
     pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=MapEventJournalReadResponseParams> + Send + Sync + 'a>> {
         Box::pin(async move {
             let initial_frame = client_message.next_frame().await.unwrap();
-            #[allow(invalid_value)]
-            let mut response = unsafe { MaybeUninit::<ClientAuthenticationResponseParams>::zeroed().assume_init() };
+            let mut response = MapEventJournalReadResponseParams::default();
 
             response.read_count = FixSizedTypesCodec::decode_int(&*initial_frame.content.lock().await, Self::RESPONSE_READ_COUNT_OFFSET).await;
             response.next_seq = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_NEXT_SEQ_OFFSET).await;
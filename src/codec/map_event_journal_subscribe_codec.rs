@@ -1,6 +1,5 @@
 use crate::codec_builtin::string_codec::StringCodec;
 
-use std::mem::MaybeUninit;
 use crate::protocol::client_message::{ClientMessage, Frame};
 use crate::util::bits_util::BitsUtil;
 use core::pin::Pin;
@@ -48,8 +47,7 @@ impl MapEventJournalSubscribeCodec {
     pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=MapEventJournalSubscribeResponseParams> + Send + Sync + 'a>> {
         Box::pin(async move {
             let initial_frame = client_message.next_frame().await.unwrap();
-            #[allow(invalid_value)]
-            let mut response = unsafe { MaybeUninit::<ClientAuthenticationResponseParams>::zeroed().assume_init() };
+            let mut response = MapEventJournalSubscribeResponseParams::default();
 
             response.oldest_sequence = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_OLDEST_SEQUENCE_OFFSET).await;
             response.newest_sequence = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_NEWEST_SEQUENCE_OFFSET).await;
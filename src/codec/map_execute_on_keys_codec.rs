@@ -42,7 +42,7 @@ impl MapExecuteOnKeysCodec {
     }
 
 
-    pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Array<[Data, Data]>> + Send + Sync + 'a>> {
+    pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Vec<(HeapData, HeapData)>> + Send + Sync + 'a>> {
         Box::pin(async move {
             // empty initial frame
             client_message.next_frame().await.unwrap();
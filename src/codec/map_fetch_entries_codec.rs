@@ -1,10 +1,9 @@
 use crate::codec_builtin::string_codec::StringCodec;
-use crate::codec_builtin::codec_builtin::EntryListIntegerIntegerCodec;
+use crate::codec_builtin::entry_list_integer_integer_codec::EntryListIntegerIntegerCodec;
 use crate::codec_builtin::entry_list_codec::EntryListCodec;
 use crate::codec_builtin::data_codec::DataCodec;
 use crate::serialization::heap_data::HeapData;
 
-use std::mem::MaybeUninit;
 use crate::protocol::client_message::{ClientMessage, Frame};
 use crate::util::bits_util::BitsUtil;
 use core::pin::Pin;
@@ -16,7 +15,7 @@ use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
 #[derive(Default, Clone)]
 pub struct MapFetchEntriesResponseParams {
     pub iteration_pointers: Vec<(i32, i32)>,
-    pub entries: Array<[Data, Data]>,
+    pub entries: Vec<(HeapData, HeapData)>,
 }
 
 
@@ -54,8 +53,7 @@ impl MapFetchEntriesCodec {
         Box::pin(async move {
             // empty initial frame
             client_message.next_frame().await.unwrap();
-            #[allow(invalid_value)]
-            let mut response = unsafe { MaybeUninit::<ClientAuthenticationResponseParams>::zeroed().assume_init() };
+            let mut response = MapFetchEntriesResponseParams::default();
 
             response.iteration_pointers = EntryListIntegerIntegerCodec::decode(client_message).await;
             response.entries = EntryListCodec::decode(client_message, DataCodec::decode, DataCodec::decode).await;
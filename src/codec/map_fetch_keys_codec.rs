@@ -1,10 +1,9 @@
 use crate::codec_builtin::string_codec::StringCodec;
-use crate::codec_builtin::codec_builtin::EntryListIntegerIntegerCodec;
+use crate::codec_builtin::entry_list_integer_integer_codec::EntryListIntegerIntegerCodec;
 use crate::serialization::heap_data::HeapData;
 use crate::codec_builtin::list_multi_frame_codec::ListMultiFrameCodec;
 use crate::codec_builtin::data_codec::DataCodec;
 
-use std::mem::MaybeUninit;
 use crate::protocol::client_message::{ClientMessage, Frame};
 use crate::util::bits_util::BitsUtil;
 use core::pin::Pin;
@@ -54,8 +53,7 @@ impl MapFetchKeysCodec {
         Box::pin(async move {
             // empty initial frame
             client_message.next_frame().await.unwrap();
-            #[allow(invalid_value)]
-            let mut response = unsafe { MaybeUninit::<ClientAuthenticationResponseParams>::zeroed().assume_init() };
+            let mut response = MapFetchKeysResponseParams::default();
 
             response.iteration_pointers = EntryListIntegerIntegerCodec::decode(client_message).await;
             response.keys = ListMultiFrameCodec::decode(client_message, DataCodec::decode).await;
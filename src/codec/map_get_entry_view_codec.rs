@@ -1,11 +1,10 @@
 use crate::codec_builtin::string_codec::StringCodec;
 use crate::serialization::heap_data::HeapData;
 use crate::codec_builtin::data_codec::DataCodec;
-use crate::core::SimpleEntryView;
-use crate::codec::custom::custom::SimpleEntryViewCodec;
+use crate::core::entry_view::EntryView;
+use crate::codec::custom::simple_entry_view_codec::SimpleEntryViewCodec;
 use crate::codec_builtin::codec_util::CodecUtil;
 
-use std::mem::MaybeUninit;
 use crate::protocol::client_message::{ClientMessage, Frame};
 use crate::util::bits_util::BitsUtil;
 use core::pin::Pin;
@@ -16,7 +15,7 @@ use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
 /** @internal */
 #[derive(Default, Clone)]
 pub struct MapGetEntryViewResponseParams {
-    pub response: Option<SimpleEntryView<Data, Data>>,
+    pub response: Option<EntryView<HeapData, HeapData>>,
     pub max_idle: i64,
 }
 
@@ -55,8 +54,7 @@ impl MapGetEntryViewCodec {
     pub fn decode_response<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=MapGetEntryViewResponseParams> + Send + Sync + 'a>> {
         Box::pin(async move {
             let initial_frame = client_message.next_frame().await.unwrap();
-            #[allow(invalid_value)]
-            let mut response = unsafe { MaybeUninit::<ClientAuthenticationResponseParams>::zeroed().assume_init() };
+            let mut response = MapGetEntryViewResponseParams::default();
 
             response.max_idle = FixSizedTypesCodec::decode_long(&*initial_frame.content.lock().await, Self::RESPONSE_MAX_IDLE_OFFSET).await;
             response.response = CodecUtil::decode_nullable(client_message, SimpleEntryViewCodec::decode).await;
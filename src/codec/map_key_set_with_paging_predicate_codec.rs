@@ -1,13 +1,12 @@
 use crate::codec_builtin::string_codec::StringCodec;
-use crate::protocol::PagingPredicateHolder;
-use crate::codec::custom::custom::PagingPredicateHolderCodec;
+use crate::protocol::paging_predicate_holder::PagingPredicateHolder;
+use crate::codec::custom::paging_predicate_holder_codec::PagingPredicateHolderCodec;
 use crate::serialization::heap_data::HeapData;
 use crate::codec_builtin::list_multi_frame_codec::ListMultiFrameCodec;
 use crate::codec_builtin::data_codec::DataCodec;
-use crate::protocol::AnchorDataListHolder;
-use crate::codec::custom::custom::AnchorDataListHolderCodec;
+use crate::protocol::anchor_data_list_holder::AnchorDataListHolder;
+use crate::codec::custom::anchor_data_list_holder_codec::AnchorDataListHolderCodec;
 
-use std::mem::MaybeUninit;
 use crate::protocol::client_message::{ClientMessage, Frame};
 use crate::util::bits_util::BitsUtil;
 use core::pin::Pin;
@@ -54,8 +53,7 @@ impl MapKeySetWithPagingPredicateCodec {
         Box::pin(async move {
             // empty initial frame
             client_message.next_frame().await.unwrap();
-            #[allow(invalid_value)]
-            let mut response = unsafe { MaybeUninit::<ClientAuthenticationResponseParams>::zeroed().assume_init() };
+            let mut response = MapKeySetWithPagingPredicateResponseParams::default();
 
             response.response = ListMultiFrameCodec::decode(client_message, DataCodec::decode).await;
             response.anchor_data_list = AnchorDataListHolderCodec::decode(client_message).await;
@@ -24,7 +24,7 @@ impl MapPutAllCodec {
     const REQUEST_TRIGGER_MAP_LOADER_OFFSET: usize = ClientMessage::PARTITION_ID_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
     const REQUEST_INITIAL_FRAME_SIZE: usize = Self::REQUEST_TRIGGER_MAP_LOADER_OFFSET + BitsUtil::BOOLEAN_SIZE_IN_BYTES as usize;
 
-    pub fn encode_request<'a>(name: &'a String, entries: &'a Array<[Data, Data]>, trigger_map_loader: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    pub fn encode_request<'a>(name: &'a String, entries: &'a Vec<(HeapData, HeapData)>, trigger_map_loader: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
         Box::pin(async move {
             let mut client_message = ClientMessage::create_for_encode().await;
             client_message.retryable = false;
@@ -1,7 +1,7 @@
 use crate::codec_builtin::string_codec::StringCodec;
 use crate::codec_builtin::entry_list_codec::EntryListCodec;
 use crate::codec_builtin::data_codec::DataCodec;
-use crate::codec_builtin::codec_builtin::ListDataCodec;
+use crate::codec_builtin::list_data_codec::ListDataCodec;
 use crate::serialization::heap_data::HeapData;
 
 use std::mem::MaybeUninit;
@@ -23,7 +23,7 @@ impl MultiMapPutAllCodec {
 
     const REQUEST_INITIAL_FRAME_SIZE: usize = ClientMessage::PARTITION_ID_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
 
-    pub fn encode_request<'a>(name: &'a String, entries: &'a Array<[Data, Data[]]>) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    pub fn encode_request<'a>(name: &'a String, entries: &'a Vec<(HeapData, Vec<HeapData>)>) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
         Box::pin(async move {
             let mut client_message = ClientMessage::create_for_encode().await;
             client_message.retryable = false;
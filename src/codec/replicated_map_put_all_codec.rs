@@ -0,0 +1,43 @@
+use crate::codec_builtin::string_codec::StringCodec;
+use crate::codec_builtin::entry_list_codec::EntryListCodec;
+use crate::serialization::heap_data::HeapData;
+use crate::codec_builtin::data_codec::DataCodec;
+
+use std::mem::MaybeUninit;
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::util::bits_util::BitsUtil;
+use core::pin::Pin;
+use std::future::Future;
+
+
+
+pub struct ReplicatedMapPutAllCodec;
+
+impl ReplicatedMapPutAllCodec {
+
+    // hex: 0x0D0800
+    const REQUEST_MESSAGE_TYPE: i32 = 854016;
+    // hex: 0x0D0801
+    // RESPONSE_MESSAGE_TYPE = 854017
+
+    const REQUEST_INITIAL_FRAME_SIZE: usize = ClientMessage::PARTITION_ID_OFFSET as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
+
+    pub fn encode_request<'a>(name: &'a String, entries: &'a Vec<(HeapData, HeapData)>) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+        Box::pin(async move {
+            let mut client_message = ClientMessage::create_for_encode().await;
+            client_message.retryable = false;
+
+            let initial_frame = Frame::create_initial_frame(Self::REQUEST_INITIAL_FRAME_SIZE, None);
+            client_message.add_frame(initial_frame).await;
+            client_message.set_message_type(Self::REQUEST_MESSAGE_TYPE).await;
+            client_message.set_partition_id(-1).await;
+
+            StringCodec::encode(&mut client_message, name).await;
+            EntryListCodec::encode(&mut client_message, entries, DataCodec::encode, DataCodec::encode).await;
+
+            client_message
+        })
+    }
+
+
+}
\ No newline at end of file
@@ -6,29 +6,28 @@ use crate::protocol::client_message::{ClientMessage, Frame};
 pub struct EntryListCodec;
 
 impl EntryListCodec {
-  pub fn encode<'a, K, V>(
+  pub fn encode<'a, K: Send + Sync, V: Send + Sync>(
     client_message: &'a mut ClientMessage,
-    entries: &'a mut Vec<(K, V)>,
-    key_encoder: impl for<'b> Fn(&'b mut ClientMessage, &'b mut K) -> Pin<Box<dyn Future<Output=()> + 'b>> + 'a,
-    value_encoder: impl for<'b> Fn(&'b mut ClientMessage, &'b mut V) -> Pin<Box<dyn Future<Output=()> + 'b>> + 'a,
-  ) -> Pin<Box<dyn Future<Output=()> + 'a>> {
+    entries: &'a Vec<(K, V)>,
+    key_encoder: impl for<'b> Fn(&'b mut ClientMessage, &'b K) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'b>> + Send + Sync + 'a,
+    value_encoder: impl for<'b> Fn(&'b mut ClientMessage, &'b V) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'b>> + Send + Sync + 'a,
+  ) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
     Box::pin(async move {
       client_message.add_frame(Frame::new_begin_frame().copy()).await;
-      let n = entries.len();
-      for i in 0..n {
-        key_encoder(client_message, &mut entries[i].0).await;
-        value_encoder(client_message, &mut entries[i].1).await;
+      for (key, value) in entries {
+        key_encoder(client_message, key).await;
+        value_encoder(client_message, value).await;
       }
       client_message.add_frame(Frame::new_end_frame().copy()).await;
     })
   }
 
-  pub fn encode_nullable<'a, K, V>(
+  pub fn encode_nullable<'a, K: Send + Sync, V: Send + Sync>(
     client_message: &'a mut ClientMessage,
-    entries: Option<&'a mut Vec<(K, V)>>,
-    key_encoder: impl for<'b> Fn(&'b mut ClientMessage, &'b mut K) -> Pin<Box<dyn Future<Output=()> + 'b>> + 'a,
-    value_encoder: impl for<'b> Fn(&'b mut ClientMessage, &'b mut V) -> Pin<Box<dyn Future<Output=()> + 'b>> + 'a,
-  ) -> Pin<Box<dyn Future<Output=()> + 'a>> {
+    entries: Option<&'a Vec<(K, V)>>,
+    key_encoder: impl for<'b> Fn(&'b mut ClientMessage, &'b K) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'b>> + Send + Sync + 'a,
+    value_encoder: impl for<'b> Fn(&'b mut ClientMessage, &'b V) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'b>> + Send + Sync + 'a,
+  ) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
     Box::pin(async move {
       if let Some(entries) = entries {
         Self::encode(client_message, entries, key_encoder, value_encoder).await;
@@ -38,27 +37,35 @@ impl EntryListCodec {
     })
   }
 
-  pub async fn decode<K, V>(
-    client_message: &mut ClientMessage,
-    key_decoder: impl Fn(&mut ClientMessage) -> K,
-    value_decoder: impl Fn(&mut ClientMessage) -> V) -> Vec<(K, V)> {
-    let mut result = vec![];
-    client_message.next_frame().await;
-    while !CodecUtil::next_frame_is_data_structure_end_frame(client_message).await {
-      let key = key_decoder(client_message);
-      let value = value_decoder(client_message);
-      result.push((key, value));
-    }
-    client_message.next_frame().await;
-    result
+  pub fn decode<'a, K: Send + Sync, V: Send + Sync>(
+    client_message: &'a mut ClientMessage,
+    key_decoder: impl for<'b> Fn(&'b mut ClientMessage) -> Pin<Box<dyn Future<Output=K> + Send + Sync + 'b>> + Send + Sync + 'a,
+    value_decoder: impl for<'b> Fn(&'b mut ClientMessage) -> Pin<Box<dyn Future<Output=V> + Send + Sync + 'b>> + Send + Sync + 'a,
+  ) -> Pin<Box<dyn Future<Output=Vec<(K, V)>> + Send + Sync + 'a>> {
+    Box::pin(async move {
+      let mut result = vec![];
+      client_message.next_frame().await;
+      while !CodecUtil::next_frame_is_data_structure_end_frame(client_message).await {
+        let key = key_decoder(client_message).await;
+        let value = value_decoder(client_message).await;
+        result.push((key, value));
+      }
+      client_message.next_frame().await;
+      result
+    })
   }
 
-  pub async fn decode_nullable<K, V>(client_message: &mut ClientMessage,
-                                     key_decoder: impl Fn(&mut ClientMessage) -> K, value_decoder: impl Fn(&mut ClientMessage) -> V) -> Option<Vec<(K, V)>> {
-    if CodecUtil::next_frame_is_null_frame(client_message).await {
-      None
-    } else {
-      Some(Self::decode(client_message, key_decoder, value_decoder).await)
-    }
+  pub fn decode_nullable<'a, K: Send + Sync, V: Send + Sync>(
+    client_message: &'a mut ClientMessage,
+    key_decoder: impl for<'b> Fn(&'b mut ClientMessage) -> Pin<Box<dyn Future<Output=K> + Send + Sync + 'b>> + Send + Sync + 'a,
+    value_decoder: impl for<'b> Fn(&'b mut ClientMessage) -> Pin<Box<dyn Future<Output=V> + Send + Sync + 'b>> + Send + Sync + 'a,
+  ) -> Pin<Box<dyn Future<Output=Option<Vec<(K, V)>>> + Send + Sync + 'a>> {
+    Box::pin(async move {
+      if CodecUtil::next_frame_is_null_frame(client_message).await {
+        None
+      } else {
+        Some(Self::decode(client_message, key_decoder, value_decoder).await)
+      }
+    })
   }
-}
\ No newline at end of file
+}
@@ -0,0 +1,37 @@
+use std::pin::Pin;
+
+use futures::Future;
+
+use crate::codec_builtin::fix_sized_types_codec::FixSizedTypesCodec;
+use crate::protocol::client_message::{ClientMessage, Frame};
+use crate::util::bits_util::BitsUtil;
+
+pub struct EntryListIntegerIntegerCodec;
+
+impl EntryListIntegerIntegerCodec {
+  const ENTRY_SIZE_IN_BYTES: usize = BitsUtil::INT_SIZE_IN_BYTES as usize + BitsUtil::INT_SIZE_IN_BYTES as usize;
+
+  pub async fn encode(client_message: &mut ClientMessage, entries: &Vec<(i32, i32)>) {
+    let entry_count = entries.len();
+    let frame = Frame::new_default_flags(vec![0; entry_count * Self::ENTRY_SIZE_IN_BYTES]);
+    for i in 0..entry_count {
+      FixSizedTypesCodec::encode_int(&mut *frame.content.lock().await, i * Self::ENTRY_SIZE_IN_BYTES, &entries[i].0).await;
+      FixSizedTypesCodec::encode_int(&mut *frame.content.lock().await, i * Self::ENTRY_SIZE_IN_BYTES + BitsUtil::INT_SIZE_IN_BYTES as usize, &entries[i].1).await;
+    }
+    client_message.add_frame(frame).await;
+  }
+
+  pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Vec<(i32, i32)>> + Send + Sync + 'a>> {
+    Box::pin(async move {
+      let frame = client_message.next_frame().await.unwrap();
+      let entry_count = frame.content.lock().await.len() / Self::ENTRY_SIZE_IN_BYTES;
+      let mut result = Vec::with_capacity(entry_count);
+      for i in 0..entry_count {
+        let key = FixSizedTypesCodec::decode_int(&*frame.content.lock().await, i * Self::ENTRY_SIZE_IN_BYTES).await;
+        let value = FixSizedTypesCodec::decode_int(&*frame.content.lock().await, i * Self::ENTRY_SIZE_IN_BYTES + BitsUtil::INT_SIZE_IN_BYTES as usize).await;
+        result.push((key, value));
+      }
+      result
+    })
+  }
+}
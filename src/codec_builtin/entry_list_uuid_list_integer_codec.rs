@@ -16,7 +16,7 @@ impl EntryListUUIDListIntegerCodec {
       client_message.add_frame(Frame::new_begin_frame().copy()).await;
       for i in 0..entry_count {
         keys[i] = entries[i].0;
-        ListIntegerCodec::encode(client_message, &mut entries[i].1).await;
+        ListIntegerCodec::encode(client_message, &entries[i].1).await;
       }
       client_message.add_frame(Frame::new_end_frame().copy()).await;
       ListUUIDCodec::encode(client_message, &mut keys).await;
@@ -13,8 +13,7 @@ impl ErrorCodec {
   pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Vec<ErrorHolder>> + Send + Sync + 'a>> {
     Box::pin(async move {
       client_message.next_frame().await;
-      ListMultiFrameCodec::decode(client_message, ErrorHolderCodec::decode).await;
-      vec![]
+      ListMultiFrameCodec::decode(client_message, ErrorHolderCodec::decode).await
     })
   }
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use std::future::Future;
+use std::pin::Pin;
+use crate::codec_builtin::data_codec::DataCodec;
+use crate::codec_builtin::list_multi_frame_codec::ListMultiFrameCodec;
+use crate::protocol::client_message::ClientMessage;
+use crate::serialization::heap_data::HeapData;
+
+pub struct ListDataCodec;
+
+impl ListDataCodec {
+  pub fn encode<'a>(client_message: &'a mut ClientMessage, list: &'a Vec<HeapData>) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
+    ListMultiFrameCodec::encode(client_message, list, DataCodec::encode)
+  }
+
+  pub fn decode<'a>(client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Vec<HeapData>> + Send + Sync + 'a>> {
+    ListMultiFrameCodec::decode(client_message, DataCodec::decode)
+  }
+}
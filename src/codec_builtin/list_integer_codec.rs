@@ -9,7 +9,7 @@ use crate::util::bits_util::BitsUtil;
 pub struct ListIntegerCodec;
 
 impl ListIntegerCodec {
-  pub async fn encode(client_message: &mut ClientMessage, list: &mut Vec<i32>) {
+  pub async fn encode(client_message: &mut ClientMessage, list: &Vec<i32>) {
     let item_count = list.len();
     let mut frame = Frame::new_default_flags(vec![0; item_count * BitsUtil::INT_SIZE_IN_BYTES as usize]);
     for i in 0..item_count {
@@ -85,10 +85,10 @@ impl ListMultiFrameCodec {
     })
   }
 
-  pub fn decode_contains_nullable<'a, T>(
+  pub fn decode_contains_nullable<'a, T: Send + Sync>(
     client_message: &'a mut ClientMessage,
-    decoder: impl for<'b> Fn(&'b mut ClientMessage) -> Pin<Box<dyn Future<Output=T> + 'b>> + 'a,
-  ) -> Pin<Box<dyn Future<Output=Vec<Option<T>>> + 'a>> {
+    decoder: impl for<'b> Fn(&'b mut ClientMessage) -> Pin<Box<dyn Future<Output=T> + Send + Sync + 'b>> + Send + Sync + 'a,
+  ) -> Pin<Box<dyn Future<Output=Vec<Option<T>>> + Send + Sync + 'a>> {
     Box::pin(async move {
       let mut result = vec![];
       client_message.next_frame().await;
@@ -13,3 +13,6 @@ pub mod error_codec;
 pub mod error_holder_codec;
 pub mod stack_trace_element_codec;
 pub mod data_codec;
+pub mod entry_list_integer_integer_codec;
+pub mod long_array_codec;
+pub mod list_data_codec;
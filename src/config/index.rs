@@ -0,0 +1,304 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndexType {
+  #[default]
+  Sorted,
+  Hash,
+  Bitmap,
+  /// A type sent by a newer member that the client does not know.
+  Unknown(i32),
+}
+
+impl IndexType {
+  pub fn id(&self) -> i32 {
+    match self {
+      IndexType::Sorted => 0,
+      IndexType::Hash => 1,
+      IndexType::Bitmap => 2,
+      IndexType::Unknown(id) => *id,
+    }
+  }
+
+  pub fn from_id(id: i32) -> Self {
+    match id {
+      0 => IndexType::Sorted,
+      1 => IndexType::Hash,
+      2 => IndexType::Bitmap,
+      id => IndexType::Unknown(id),
+    }
+  }
+
+  fn name(&self) -> &'static str {
+    match self {
+      IndexType::Sorted => "sorted",
+      IndexType::Hash => "hash",
+      IndexType::Bitmap => "bitmap",
+      IndexType::Unknown(_) => "unknown",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UniqueKeyTransformation {
+  #[default]
+  Object,
+  Long,
+  Raw,
+  /// A transformation sent by a newer member that the client does not know.
+  Unknown(i32),
+}
+
+impl UniqueKeyTransformation {
+  pub fn id(&self) -> i32 {
+    match self {
+      UniqueKeyTransformation::Object => 0,
+      UniqueKeyTransformation::Long => 1,
+      UniqueKeyTransformation::Raw => 2,
+      UniqueKeyTransformation::Unknown(id) => *id,
+    }
+  }
+
+  pub fn from_id(id: i32) -> Self {
+    match id {
+      0 => UniqueKeyTransformation::Object,
+      1 => UniqueKeyTransformation::Long,
+      2 => UniqueKeyTransformation::Raw,
+      id => UniqueKeyTransformation::Unknown(id),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitmapIndexOptions {
+  pub unique_key: String,
+  pub unique_key_transformation: UniqueKeyTransformation,
+}
+
+impl BitmapIndexOptions {
+  pub const DEFAULT_UNIQUE_KEY: &'static str = "__key";
+
+  pub fn unique_key(mut self, unique_key: impl ToString) -> Self {
+    self.unique_key = unique_key.to_string();
+    self
+  }
+
+  pub fn unique_key_transformation(mut self, unique_key_transformation: UniqueKeyTransformation) -> Self {
+    self.unique_key_transformation = unique_key_transformation;
+    self
+  }
+}
+
+impl Default for BitmapIndexOptions {
+  fn default() -> Self {
+    Self {
+      unique_key: Self::DEFAULT_UNIQUE_KEY.to_string(),
+      unique_key_transformation: UniqueKeyTransformation::default(),
+    }
+  }
+}
+
+/// Returned by [`IndexConfig::normalize`] for configs the members would reject.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidIndexConfigError {
+  NoAttributes,
+  TooManyAttributes { count: usize },
+  CompositeBitmap,
+  EmptyAttribute,
+  TrailingDot { attribute: String },
+  DuplicateAttribute { attribute: String },
+}
+
+impl Display for InvalidIndexConfigError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      InvalidIndexConfigError::NoAttributes => write!(f, "index must have at least one attribute"),
+      InvalidIndexConfigError::TooManyAttributes { count } => write!(f, "index cannot have more than {} attributes, got {}", IndexConfig::MAX_ATTRIBUTES, count),
+      InvalidIndexConfigError::CompositeBitmap => write!(f, "composite bitmap indexes are not supported"),
+      InvalidIndexConfigError::EmptyAttribute => write!(f, "attribute name cannot be empty"),
+      InvalidIndexConfigError::TrailingDot { attribute } => write!(f, "attribute name cannot end with dot: {}", attribute),
+      InvalidIndexConfigError::DuplicateAttribute { attribute } => write!(f, "duplicate attribute name: {}", attribute),
+    }
+  }
+}
+
+impl std::error::Error for InvalidIndexConfigError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IndexConfig {
+  pub name: Option<String>,
+  pub index_type: IndexType,
+  pub attributes: Vec<String>,
+  pub bitmap_index_options: Option<BitmapIndexOptions>,
+}
+
+impl IndexConfig {
+  pub const MAX_ATTRIBUTES: usize = 255;
+
+  pub fn new(index_type: IndexType, attributes: impl IntoIterator<Item=impl ToString>) -> Self {
+    Self {
+      index_type,
+      attributes: attributes.into_iter().map(|attribute| attribute.to_string()).collect(),
+      ..Default::default()
+    }
+  }
+
+  pub fn name(mut self, name: impl ToString) -> Self {
+    self.name = Some(name.to_string());
+    self
+  }
+
+  pub fn index_type(mut self, index_type: IndexType) -> Self {
+    self.index_type = index_type;
+    self
+  }
+
+  pub fn attribute(mut self, attribute: impl ToString) -> Self {
+    self.attributes.push(attribute.to_string());
+    self
+  }
+
+  pub fn bitmap_index_options(mut self, bitmap_index_options: BitmapIndexOptions) -> Self {
+    self.bitmap_index_options = Some(bitmap_index_options);
+    self
+  }
+
+  /// Validates the attributes and fills in what the member expects to be
+  /// set: canonical attribute names, a generated name and bitmap options.
+  pub fn normalize(&self, map_name: &str) -> Result<InternalIndexConfig, InvalidIndexConfigError> {
+    if self.attributes.is_empty() {
+      return Err(InvalidIndexConfigError::NoAttributes);
+    }
+    if self.attributes.len() > Self::MAX_ATTRIBUTES {
+      return Err(InvalidIndexConfigError::TooManyAttributes { count: self.attributes.len() });
+    }
+    if self.index_type == IndexType::Bitmap && self.attributes.len() > 1 {
+      return Err(InvalidIndexConfigError::CompositeBitmap);
+    }
+
+    let mut attributes: Vec<String> = vec![];
+    for attribute in &self.attributes {
+      let attribute = Self::canonicalize_attribute(attribute);
+      if attribute.is_empty() {
+        return Err(InvalidIndexConfigError::EmptyAttribute);
+      }
+      if attribute.ends_with('.') {
+        return Err(InvalidIndexConfigError::TrailingDot { attribute });
+      }
+      if attributes.contains(&attribute) {
+        return Err(InvalidIndexConfigError::DuplicateAttribute { attribute });
+      }
+      attributes.push(attribute);
+    }
+
+    let name = self.name.clone().unwrap_or_else(|| {
+      let mut name = format!("{}_{}", map_name, self.index_type.name());
+      for attribute in &attributes {
+        name.push('_');
+        name.push_str(attribute);
+      }
+      name
+    });
+
+    let bitmap_index_options = if self.index_type == IndexType::Bitmap {
+      let bitmap_index_options = self.bitmap_index_options.clone().unwrap_or_default();
+      Some(BitmapIndexOptions {
+        unique_key: Self::canonicalize_attribute(&bitmap_index_options.unique_key),
+        unique_key_transformation: bitmap_index_options.unique_key_transformation,
+      })
+    } else {
+      None
+    };
+
+    Ok(InternalIndexConfig {
+      name,
+      index_type: self.index_type,
+      attributes,
+      bitmap_index_options,
+    })
+  }
+
+  fn canonicalize_attribute(attribute: &str) -> String {
+    let attribute = attribute.trim();
+    attribute.strip_prefix("this.").unwrap_or(attribute).to_string()
+  }
+}
+
+/// An [`IndexConfig`] as sent to the member, see [`IndexConfig::normalize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InternalIndexConfig {
+  pub name: String,
+  pub index_type: IndexType,
+  pub attributes: Vec<String>,
+  pub bitmap_index_options: Option<BitmapIndexOptions>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rejects_missing_attributes() {
+    let config = IndexConfig::new(IndexType::Sorted, Vec::<String>::new());
+    assert_eq!(config.normalize("map"), Err(InvalidIndexConfigError::NoAttributes));
+  }
+
+  #[test]
+  fn rejects_too_many_attributes() {
+    let config = IndexConfig::new(IndexType::Sorted, (0..=IndexConfig::MAX_ATTRIBUTES).map(|i| format!("a{}", i)));
+    assert_eq!(config.normalize("map"), Err(InvalidIndexConfigError::TooManyAttributes { count: IndexConfig::MAX_ATTRIBUTES + 1 }));
+  }
+
+  #[test]
+  fn rejects_composite_bitmap() {
+    let config = IndexConfig::new(IndexType::Bitmap, ["a", "b"]);
+    assert_eq!(config.normalize("map"), Err(InvalidIndexConfigError::CompositeBitmap));
+  }
+
+  #[test]
+  fn rejects_empty_attribute() {
+    let config = IndexConfig::new(IndexType::Sorted, ["  "]);
+    assert_eq!(config.normalize("map"), Err(InvalidIndexConfigError::EmptyAttribute));
+  }
+
+  #[test]
+  fn rejects_trailing_dot() {
+    let config = IndexConfig::new(IndexType::Sorted, ["a."]);
+    assert_eq!(config.normalize("map"), Err(InvalidIndexConfigError::TrailingDot { attribute: "a.".to_string() }));
+  }
+
+  #[test]
+  fn rejects_duplicate_after_canonicalization() {
+    let config = IndexConfig::new(IndexType::Hash, ["a", " this.a "]);
+    assert_eq!(config.normalize("map"), Err(InvalidIndexConfigError::DuplicateAttribute { attribute: "a".to_string() }));
+  }
+
+  #[test]
+  fn canonicalizes_attributes_and_generates_name() {
+    let config = IndexConfig::new(IndexType::Sorted, [" this.a ", "b.c"]);
+    let normalized = config.normalize("map").unwrap();
+    assert_eq!(normalized.name, "map_sorted_a_b.c");
+    assert_eq!(normalized.attributes, vec!["a".to_string(), "b.c".to_string()]);
+    assert_eq!(normalized.bitmap_index_options, None);
+  }
+
+  #[test]
+  fn keeps_explicit_name() {
+    let config = IndexConfig::new(IndexType::Hash, ["a"]).name("idx");
+    assert_eq!(config.normalize("map").unwrap().name, "idx");
+  }
+
+  #[test]
+  fn fills_in_bitmap_options() {
+    let config = IndexConfig::new(IndexType::Bitmap, ["a"])
+      .bitmap_index_options(BitmapIndexOptions::default().unique_key("this.id"));
+    let normalized = config.normalize("map").unwrap();
+    assert_eq!(normalized.bitmap_index_options, Some(BitmapIndexOptions {
+      unique_key: "id".to_string(),
+      unique_key_transformation: UniqueKeyTransformation::Object,
+    }));
+
+    let normalized = IndexConfig::new(IndexType::Bitmap, ["a"]).normalize("map").unwrap();
+    assert_eq!(normalized.bitmap_index_options, Some(BitmapIndexOptions::default()));
+  }
+}
@@ -1,7 +1,9 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{RwLock, RwLockWriteGuard};
 use crate::ClientNetworkConfig;
 use crate::config::connection::ConnectionStrategyConfig;
+use crate::config::reliable_topic::ReliableTopicConfig;
 use crate::config::retry::ClientRetryConfig;
 use crate::config::security::SecurityConfig;
 use crate::config::serialization::SerializationConfig;
@@ -11,6 +13,8 @@ pub mod retry;
 pub mod connection;
 pub mod serialization;
 pub mod security;
+pub mod index;
+pub mod reliable_topic;
 
 #[derive(Default)]
 pub struct ClientConfig {
@@ -21,6 +25,7 @@ pub struct ClientConfig {
   pub cluster_name: String,
   pub client_name: String,
   pub serialization: Arc<RwLock<SerializationConfig>>,
+  pub reliable_topics: Arc<RwLock<HashMap<String, ReliableTopicConfig>>>,
 }
 
 impl ClientConfig {
@@ -46,4 +51,16 @@ impl ClientConfig {
     callback(security);
     self
   }
+
+  pub async fn reliable_topic<F: FnOnce(&mut ReliableTopicConfig)>(self, name: String, callback: F) -> Self {
+    let mut reliable_topics = self.reliable_topics.write().await;
+    callback(reliable_topics.entry(name).or_default());
+    drop(reliable_topics);
+    self
+  }
+
+  /// Falls back to the defaults for topics without their own config.
+  pub async fn get_reliable_topic_config(&self, name: &str) -> ReliableTopicConfig {
+    self.reliable_topics.read().await.get(name).cloned().unwrap_or_default()
+  }
 }
\ No newline at end of file
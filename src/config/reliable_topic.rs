@@ -0,0 +1,29 @@
+/// What a reliable topic does when publishing would overwrite messages that
+/// are still within the ringbuffer's time to live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopicOverloadPolicy {
+  /// Overwrites the oldest message, even if listeners have not read it yet.
+  DiscardOldest,
+  /// Drops the message that is being published.
+  DiscardNewest,
+  /// Retries with an exponential backoff until there is room.
+  Block,
+  /// Returns a `TopicOverloadError` from `publish`.
+  Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReliableTopicConfig {
+  /// Maximum number of messages a listener reads from the ringbuffer at once.
+  pub read_batch_size: i32,
+  pub overload_policy: TopicOverloadPolicy,
+}
+
+impl Default for ReliableTopicConfig {
+  fn default() -> Self {
+    Self {
+      read_batch_size: 10,
+      overload_policy: TopicOverloadPolicy::Block,
+    }
+  }
+}
@@ -3,6 +3,7 @@ use num_bigint::BigInt;
 use num_traits::identities::Zero;
 use num_traits::Signed;
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct BigDecimal {
   pub unscaled_value: BigInt,
   pub scale: i32,
@@ -0,0 +1,36 @@
+/// A snapshot of a map entry together with its statistics. Times are
+/// milliseconds since the epoch, durations are milliseconds.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EntryView<K, V> {
+  pub key: K,
+  pub value: V,
+  pub cost: i64,
+  pub creation_time: i64,
+  pub expiration_time: i64,
+  pub hits: i64,
+  pub last_access_time: i64,
+  pub last_stored_time: i64,
+  pub last_update_time: i64,
+  pub version: i64,
+  pub ttl: i64,
+  pub max_idle: i64,
+}
+
+impl<K, V> EntryView<K, V> {
+  pub fn map<K2, V2>(self, key: K2, value: V2) -> EntryView<K2, V2> {
+    EntryView {
+      key,
+      value,
+      cost: self.cost,
+      creation_time: self.creation_time,
+      expiration_time: self.expiration_time,
+      hits: self.hits,
+      last_access_time: self.last_access_time,
+      last_stored_time: self.last_stored_time,
+      last_update_time: self.last_update_time,
+      version: self.version,
+      ttl: self.ttl,
+      max_idle: self.max_idle,
+    }
+  }
+}
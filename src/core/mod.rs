@@ -3,4 +3,5 @@ pub mod member;
 pub mod big_decimal;
 pub mod rest_value;
 pub mod distributed_object;
-pub mod readonly_lazy_list;
\ No newline at end of file
+pub mod readonly_lazy_list;
+pub mod entry_view;
\ No newline at end of file
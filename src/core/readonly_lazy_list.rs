@@ -1,20 +1,71 @@
-use std::any::Any;
-use std::marker::PhantomData;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use crate::serialization::heap_data::HeapData;
 use crate::serialization::service::SerializationServiceV1;
 
-pub struct ReadOnlyLazyList<T> {
-  internal_list: Arc<Mutex<Vec<Box<dyn Any>>>>,
-  serializatiion_service: Arc<SerializationServiceV1>,
-  _phantom: PhantomData<T>,
+pub type LazyDeserializer<T, D> = fn(Arc<SerializationServiceV1>, D) -> Pin<Box<dyn Future<Output=T> + Send + Sync>>;
+
+enum LazyItem<T, D> {
+  Data(D),
+  Object(T),
+}
+
+/// A list of serialized items that are deserialized on first access and
+/// cached afterwards.
+pub struct ReadOnlyLazyList<T, D = HeapData> {
+  internal_list: Arc<Mutex<Vec<LazyItem<T, D>>>>,
+  serialization_service: Arc<SerializationServiceV1>,
+  deserializer: LazyDeserializer<T, D>,
+  len: usize,
 }
 
-impl<T> ReadOnlyLazyList<T> {
+impl<T: Clone + Send + Sync + 'static, D: Clone + Send + Sync + 'static> ReadOnlyLazyList<T, D> {
+  pub fn new(items: Vec<D>, serialization_service: Arc<SerializationServiceV1>, deserializer: LazyDeserializer<T, D>) -> Self {
+    Self {
+      len: items.len(),
+      internal_list: Arc::new(Mutex::new(items.into_iter().map(LazyItem::Data).collect())),
+      serialization_service,
+      deserializer,
+    }
+  }
+
   pub async fn get(&self, index: usize) -> Option<T> {
     let mut internal_list = self.internal_list.lock().await;
-    internal_list.get_mut(index).map(|item| {
-      todo!()
-    })
+    let item = internal_list.get_mut(index)?;
+    let object = match item {
+      LazyItem::Object(object) => return Some(object.clone()),
+      LazyItem::Data(data) => (self.deserializer)(self.serialization_service.clone(), data.clone()).await,
+    };
+    *item = LazyItem::Object(object.clone());
+    Some(object)
+  }
+
+  pub fn len(&self) -> usize {
+    self.len
   }
-}
\ No newline at end of file
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  pub async fn to_vec(&self) -> Vec<T> {
+    let mut result = Vec::with_capacity(self.len);
+    for index in 0..self.len {
+      result.push(self.get(index).await.unwrap());
+    }
+    result
+  }
+}
+
+impl<T, D> Clone for ReadOnlyLazyList<T, D> {
+  fn clone(&self) -> Self {
+    Self {
+      internal_list: self.internal_list.clone(),
+      serialization_service: self.serialization_service.clone(),
+      deserializer: self.deserializer,
+      len: self.len,
+    }
+  }
+}
@@ -0,0 +1,202 @@
+use crate::codec::atomic_long_add_and_get_codec::AtomicLongAddAndGetCodec;
+use crate::codec::atomic_long_alter_codec::AtomicLongAlterCodec;
+use crate::codec::atomic_long_apply_codec::AtomicLongApplyCodec;
+use crate::codec::atomic_long_compare_and_set_codec::AtomicLongCompareAndSetCodec;
+use crate::codec::atomic_long_get_and_add_codec::AtomicLongGetAndAddCodec;
+use crate::codec::atomic_long_get_and_set_codec::AtomicLongGetAndSetCodec;
+use crate::codec::atomic_long_get_codec::AtomicLongGetCodec;
+use crate::cp::CpProxy;
+use crate::cp::function::Function;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::proxy::base::ProxyBase;
+
+const ALTER_RETURN_OLD_VALUE: i32 = 0;
+const ALTER_RETURN_NEW_VALUE: i32 = 1;
+
+/// A linearizable counter replicated within its CP group. Requests go to a
+/// random member, which forwards them to the leader of the group.
+#[derive(Clone)]
+pub struct AtomicLongProxy {
+  base: ProxyBase,
+  group_id: RaftGroupId,
+  proxy_name: String,
+}
+
+impl AtomicLongProxy {
+  pub fn new(
+    base: ProxyBase,
+    group_id: RaftGroupId,
+    proxy_name: String,
+  ) -> Self {
+    AtomicLongProxy {
+      base,
+      group_id,
+      proxy_name,
+    }
+  }
+
+  /// The name the proxy was requested with, without a `@default` suffix.
+  pub fn get_name(&self) -> &str {
+    &self.proxy_name
+  }
+
+  pub fn get_group_id(&self) -> &RaftGroupId {
+    &self.group_id
+  }
+
+  pub async fn get(&self) -> i64 {
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          async move {
+            AtomicLongGetCodec::encode_request(&group_id, &name).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicLongGetCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn set(&self, new_value: i64) {
+    self.get_and_set(new_value).await;
+  }
+
+  pub async fn get_and_set(&self, new_value: i64) -> i64 {
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          async move {
+            AtomicLongGetAndSetCodec::encode_request(&group_id, &name, &new_value).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicLongGetAndSetCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn add_and_get(&self, delta: i64) -> i64 {
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          async move {
+            AtomicLongAddAndGetCodec::encode_request(&group_id, &name, &delta).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicLongAddAndGetCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn get_and_add(&self, delta: i64) -> i64 {
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          async move {
+            AtomicLongGetAndAddCodec::encode_request(&group_id, &name, &delta).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicLongGetAndAddCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn increment_and_get(&self) -> i64 {
+    self.add_and_get(1).await
+  }
+
+  pub async fn decrement_and_get(&self) -> i64 {
+    self.add_and_get(-1).await
+  }
+
+  pub async fn get_and_increment(&self) -> i64 {
+    self.get_and_add(1).await
+  }
+
+  pub async fn get_and_decrement(&self) -> i64 {
+    self.get_and_add(-1).await
+  }
+
+  /// Returns `false` without changing the value if it is not `expected`.
+  pub async fn compare_and_set(&self, expected: i64, updated: i64) -> bool {
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          async move {
+            AtomicLongCompareAndSetCodec::encode_request(&group_id, &name, &expected, &updated).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicLongCompareAndSetCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Replaces the value with the result of `function`.
+  pub async fn alter<F: Function<Output=i64>>(&self, function: F) {
+    self.alter_internal(function, ALTER_RETURN_NEW_VALUE).await;
+  }
+
+  pub async fn alter_and_get<F: Function<Output=i64>>(&self, function: F) -> i64 {
+    self.alter_internal(function, ALTER_RETURN_NEW_VALUE).await
+  }
+
+  pub async fn get_and_alter<F: Function<Output=i64>>(&self, function: F) -> i64 {
+    self.alter_internal(function, ALTER_RETURN_OLD_VALUE).await
+  }
+
+  /// Runs `function` on the value without changing it.
+  pub async fn apply<F: Function>(&self, function: F) -> Option<F::Output> {
+    let function_data = self.base.to_data(Box::new(function));
+    let group_id = self.group_id.clone();
+    let result_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          let function_data = function_data.clone();
+          async move {
+            AtomicLongApplyCodec::encode_request(&group_id, &name, &function_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicLongApplyCodec::decode_response(&mut response).await)) })),
+    ).await;
+    if let Some(result_data) = result_data {
+      Some(*self.base.serialization_service.to_object::<F::Output>(result_data).await)
+    } else {
+      None
+    }
+  }
+
+  async fn alter_internal<F: Function<Output=i64>>(&self, function: F, return_value_type: i32) -> i64 {
+    let function_data = self.base.to_data(Box::new(function));
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          let function_data = function_data.clone();
+          async move {
+            AtomicLongAlterCodec::encode_request(&group_id, &name, &function_data, &return_value_type).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicLongAlterCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+}
+
+impl CpProxy for AtomicLongProxy {
+  const SERVICE_NAME: &'static str = "hz:raft:atomicLongService";
+  fn create_proxy(base: ProxyBase, group_id: RaftGroupId, proxy_name: String) -> Self {
+    Self::new(base, group_id, proxy_name)
+  }
+}
@@ -0,0 +1,199 @@
+use std::marker::PhantomData;
+use crate::codec::atomic_ref_apply_codec::AtomicRefApplyCodec;
+use crate::codec::atomic_ref_compare_and_set_codec::AtomicRefCompareAndSetCodec;
+use crate::codec::atomic_ref_contains_codec::AtomicRefContainsCodec;
+use crate::codec::atomic_ref_get_codec::AtomicRefGetCodec;
+use crate::codec::atomic_ref_set_codec::AtomicRefSetCodec;
+use crate::cp::CpProxy;
+use crate::cp::function::Function;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::proxy::base::ProxyBase;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+
+const APPLY_RETURN_NO_VALUE: i32 = 0;
+const APPLY_RETURN_OLD_VALUE: i32 = 1;
+const APPLY_RETURN_NEW_VALUE: i32 = 2;
+
+/// A linearizable reference replicated within its CP group. The value is
+/// stored serialized, so `compare_and_set` and `contains` compare the
+/// serialized forms. `None` stands for no value.
+#[derive(Clone)]
+pub struct AtomicReferenceProxy<T: Serializable> {
+  base: ProxyBase,
+  group_id: RaftGroupId,
+  proxy_name: String,
+  phantom: PhantomData<T>,
+}
+
+impl<T: Serializable + Clone + Send + Sync + 'static> AtomicReferenceProxy<T> {
+  pub fn new(
+    base: ProxyBase,
+    group_id: RaftGroupId,
+    proxy_name: String,
+  ) -> Self {
+    AtomicReferenceProxy {
+      base,
+      group_id,
+      proxy_name,
+      phantom: PhantomData::default(),
+    }
+  }
+
+  /// The name the proxy was requested with, without a `@default` suffix.
+  pub fn get_name(&self) -> &str {
+    &self.proxy_name
+  }
+
+  pub fn get_group_id(&self) -> &RaftGroupId {
+    &self.group_id
+  }
+
+  pub async fn get(&self) -> Option<T> {
+    let group_id = self.group_id.clone();
+    let value_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          async move {
+            AtomicRefGetCodec::encode_request(&group_id, &name).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicRefGetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_value(value_data).await
+  }
+
+  pub async fn set(&self, new_value: Option<T>) {
+    self.set_internal(new_value, false).await;
+  }
+
+  pub async fn get_and_set(&self, new_value: Option<T>) -> Option<T> {
+    self.set_internal(new_value, true).await
+  }
+
+  pub async fn is_null(&self) -> bool {
+    self.contains(None).await
+  }
+
+  pub async fn clear(&self) {
+    self.set(None).await
+  }
+
+  /// Returns `false` without changing the value if it is not `expected`.
+  pub async fn compare_and_set(&self, expected: Option<T>, updated: Option<T>) -> bool {
+    let expected_data = self.to_data(expected);
+    let updated_data = self.to_data(updated);
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          let expected_data = expected_data.clone();
+          let updated_data = updated_data.clone();
+          async move {
+            AtomicRefCompareAndSetCodec::encode_request(&group_id, &name, &expected_data, &updated_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicRefCompareAndSetCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains(&self, value: Option<T>) -> bool {
+    let value_data = self.to_data(value);
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          let value_data = value_data.clone();
+          async move {
+            AtomicRefContainsCodec::encode_request(&group_id, &name, &value_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicRefContainsCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Replaces the value with the result of `function`.
+  pub async fn alter<F: Function<Output=T>>(&self, function: F) {
+    self.apply_internal(function, APPLY_RETURN_NO_VALUE, true).await;
+  }
+
+  pub async fn alter_and_get<F: Function<Output=T>>(&self, function: F) -> Option<T> {
+    let value_data = self.apply_internal(function, APPLY_RETURN_NEW_VALUE, true).await;
+    self.to_value(value_data).await
+  }
+
+  pub async fn get_and_alter<F: Function<Output=T>>(&self, function: F) -> Option<T> {
+    let value_data = self.apply_internal(function, APPLY_RETURN_OLD_VALUE, true).await;
+    self.to_value(value_data).await
+  }
+
+  /// Runs `function` on the value without changing it.
+  pub async fn apply<F: Function>(&self, function: F) -> Option<F::Output> {
+    let result_data = self.apply_internal(function, APPLY_RETURN_NEW_VALUE, false).await;
+    if let Some(result_data) = result_data {
+      Some(*self.base.serialization_service.to_object::<F::Output>(result_data).await)
+    } else {
+      None
+    }
+  }
+
+  async fn set_internal(&self, new_value: Option<T>, return_old_value: bool) -> Option<T> {
+    let new_value_data = self.to_data(new_value);
+    let group_id = self.group_id.clone();
+    let old_value_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          let new_value_data = new_value_data.clone();
+          async move {
+            AtomicRefSetCodec::encode_request(&group_id, &name, &new_value_data, &return_old_value).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicRefSetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_value(old_value_data).await
+  }
+
+  async fn apply_internal<F: Function>(&self, function: F, return_value_type: i32, alter: bool) -> Option<HeapData> {
+    let function_data = self.base.to_data(Box::new(function));
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          let function_data = function_data.clone();
+          async move {
+            AtomicRefApplyCodec::encode_request(&group_id, &name, &function_data, &return_value_type, &alter).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(AtomicRefApplyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  fn to_data(&self, value: Option<T>) -> Option<HeapData> {
+    value.map(|value| self.base.to_data(Box::new(value)))
+  }
+
+  async fn to_value(&self, data: Option<HeapData>) -> Option<T> {
+    if let Some(data) = data {
+      Some(*self.base.serialization_service.to_object::<T>(data).await)
+    } else {
+      None
+    }
+  }
+}
+
+impl<T: Serializable + Clone + Send + Sync + 'static> CpProxy for AtomicReferenceProxy<T> {
+  const SERVICE_NAME: &'static str = "hz:raft:atomicRefService";
+  fn create_proxy(base: ProxyBase, group_id: RaftGroupId, proxy_name: String) -> Self {
+    Self::new(base, group_id, proxy_name)
+  }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::codec::cp_group_create_cp_group_codec::CpGroupCreateCpGroupCodec;
+use crate::cp::CpProxy;
+use crate::cp::cp_session_manager::CpSessionManager;
+use crate::cp::fenced_lock_proxy::FencedLockProxy;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::invocation::Invocation;
+use crate::proxy::manager::ProxyManager;
+
+pub const DEFAULT_GROUP_NAME: &str = "default";
+pub const METADATA_GROUP_NAME: &str = "METADATA";
+
+/// Returned for names that cannot be resolved to a CP group and object name.
+#[derive(Debug, Clone)]
+pub struct InvalidCpNameError {
+  pub name: String,
+  pub reason: &'static str,
+}
+
+impl Display for InvalidCpNameError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "invalid CP object name {:?}: {}", self.name, self.reason)
+  }
+}
+
+impl std::error::Error for InvalidCpNameError {}
+
+/// Resolves names of the form `name@group` to the CP group the object lives
+/// on. Objects without a group, or in the `default` group, live on the
+/// default CP group.
+pub struct CpProxyManager {
+  proxy_manager: Arc<ProxyManager>,
+  session_manager: Arc<CpSessionManager>,
+  lock_proxies: Mutex<HashMap<String, FencedLockProxy>>,
+}
+
+impl CpProxyManager {
+  pub fn new(proxy_manager: Arc<ProxyManager>, session_manager: Arc<CpSessionManager>) -> Self {
+    Self {
+      proxy_manager,
+      session_manager,
+      lock_proxies: Mutex::new(HashMap::new()),
+    }
+  }
+
+  pub async fn get_or_create_proxy<T: CpProxy>(&self, name: impl ToString) -> Result<T, InvalidCpNameError> {
+    let proxy_name = Self::without_default_group_name(&name.to_string())?;
+    let object_name = Self::get_object_name_for_proxy(&proxy_name)?;
+    let group_id = self.get_group_id(&proxy_name).await;
+    let base = self.proxy_manager.new_proxy_base(object_name, T::SERVICE_NAME.to_string());
+    Ok(T::create_proxy(base, group_id, proxy_name))
+  }
+
+  /// Lock proxies remember which sessions hold the lock, so there is a single
+  /// proxy per name as long as the CP group is not recreated.
+  pub async fn get_or_create_fenced_lock(&self, name: impl ToString) -> Result<FencedLockProxy, InvalidCpNameError> {
+    let proxy_name = Self::without_default_group_name(&name.to_string())?;
+    let object_name = Self::get_object_name_for_proxy(&proxy_name)?;
+    let group_id = self.get_group_id(&proxy_name).await;
+
+    let mut lock_proxies = self.lock_proxies.lock().await;
+    if let Some(lock_proxy) = lock_proxies.get(&proxy_name) {
+      if *lock_proxy.get_group_id() == group_id {
+        return Ok(lock_proxy.clone());
+      }
+    }
+    let base = self.proxy_manager.new_proxy_base(object_name, FencedLockProxy::SERVICE_NAME.to_string());
+    let lock_proxy = FencedLockProxy::new(base, group_id, proxy_name.clone(), self.session_manager.clone());
+    lock_proxies.insert(proxy_name, lock_proxy.clone());
+    Ok(lock_proxy)
+  }
+
+  /// Creates the CP group on the members if it does not exist yet.
+  pub(crate) async fn get_group_id(&self, proxy_name: &str) -> RaftGroupId {
+    let request = CpGroupCreateCpGroupCodec::encode_request(&proxy_name.to_string()).await;
+    let mut invocation = Invocation::<Box<Box<RaftGroupId>>>::new(self.proxy_manager.invocation_service.clone(), request);
+    invocation.handler = Some(Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(CpGroupCreateCpGroupCodec::decode_response(&mut response).await)) })));
+    self.proxy_manager.invocation_service.invoke(&self.proxy_manager.connection_registry, invocation).await
+  }
+
+  fn without_default_group_name(name: &str) -> Result<String, InvalidCpNameError> {
+    let invalid = |reason| InvalidCpNameError { name: name.to_string(), reason };
+    let name = name.trim();
+    let index = match name.find('@') {
+      Some(index) => index,
+      None => return Ok(name.to_string()),
+    };
+    let group_name = name[index + 1..].trim();
+    if group_name.contains('@') {
+      return Err(invalid("custom CP group name must be specified at most once"));
+    }
+    if group_name.is_empty() {
+      return Err(invalid("custom CP group name cannot be empty string"));
+    }
+    if group_name.eq_ignore_ascii_case(METADATA_GROUP_NAME) {
+      return Err(invalid("CP data structures cannot run on the METADATA CP group"));
+    }
+    if group_name.eq_ignore_ascii_case(DEFAULT_GROUP_NAME) {
+      Ok(name[..index].trim().to_string())
+    } else {
+      Ok(name.to_string())
+    }
+  }
+
+  fn get_object_name_for_proxy(proxy_name: &str) -> Result<String, InvalidCpNameError> {
+    let object_name = match proxy_name.find('@') {
+      Some(index) => proxy_name[..index].trim(),
+      None => proxy_name,
+    };
+    if object_name.is_empty() {
+      return Err(InvalidCpNameError { name: proxy_name.to_string(), reason: "object name cannot be empty string" });
+    }
+    Ok(object_name.to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keeps_names_without_group() {
+    assert_eq!(CpProxyManager::without_default_group_name(" lock ").unwrap(), "lock");
+    assert_eq!(CpProxyManager::get_object_name_for_proxy("lock").unwrap(), "lock");
+  }
+
+  #[test]
+  fn strips_default_group() {
+    assert_eq!(CpProxyManager::without_default_group_name("lock@default").unwrap(), "lock");
+    assert_eq!(CpProxyManager::without_default_group_name("lock @ DEFAULT").unwrap(), "lock");
+  }
+
+  #[test]
+  fn keeps_custom_group() {
+    let proxy_name = CpProxyManager::without_default_group_name("lock@group").unwrap();
+    assert_eq!(proxy_name, "lock@group");
+    assert_eq!(CpProxyManager::get_object_name_for_proxy(&proxy_name).unwrap(), "lock");
+  }
+
+  #[test]
+  fn rejects_metadata_group() {
+    assert!(CpProxyManager::without_default_group_name("lock@METADATA").is_err());
+    assert!(CpProxyManager::without_default_group_name("lock@metadata").is_err());
+  }
+
+  #[test]
+  fn rejects_empty_group() {
+    assert!(CpProxyManager::without_default_group_name("lock@").is_err());
+    assert!(CpProxyManager::without_default_group_name("lock@ ").is_err());
+  }
+
+  #[test]
+  fn rejects_group_specified_twice() {
+    assert!(CpProxyManager::without_default_group_name("lock@a@b").is_err());
+  }
+
+  #[test]
+  fn rejects_empty_object_name() {
+    let proxy_name = CpProxyManager::without_default_group_name("@group").unwrap();
+    assert!(CpProxyManager::get_object_name_for_proxy(&proxy_name).is_err());
+    let proxy_name = CpProxyManager::without_default_group_name("@default").unwrap();
+    assert!(CpProxyManager::get_object_name_for_proxy(&proxy_name).is_err());
+  }
+}
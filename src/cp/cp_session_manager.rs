@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use futures::future::join_all;
+use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+use crate::codec::cp_session_close_session_codec::CpSessionCloseSessionCodec;
+use crate::codec::cp_session_create_session_codec::{CpSessionCreateSessionCodec, CpSessionCreateSessionResponseParams};
+use crate::codec::cp_session_generate_thread_id_codec::CpSessionGenerateThreadIdCodec;
+use crate::codec::cp_session_heartbeat_session_codec::CpSessionHeartbeatSessionCodec;
+use crate::connection::registry::ConnectionRegistry;
+use crate::cp::fenced_lock_proxy::FencedLockError;
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::invocation::{Invocation, InvocationError, InvocationReturnValue};
+use crate::invocation::service::InvocationService;
+use crate::protocol::client_message::ClientMessage;
+use crate::util::thread_id::{MissingThreadIdScopeError, ThreadId};
+
+pub const NO_SESSION_ID: i64 = -1;
+
+const SESSION_EXPIRED_EXCEPTION: &str = "com.hazelcast.cp.internal.session.SessionExpiredException";
+
+/// Whether the members failed a request because the session it was sent with
+/// is closed. The session has to be invalidated then.
+pub fn is_session_expired(error: &InvocationError) -> bool {
+  error.class_name.as_deref() == Some(SESSION_EXPIRED_EXCEPTION)
+}
+
+struct SessionState {
+  id: i64,
+  ttl: Duration,
+  creation_time: Instant,
+  acquire_count: i64,
+}
+
+impl SessionState {
+  fn is_in_use(&self) -> bool {
+    self.acquire_count > 0
+  }
+
+  fn is_valid(&self) -> bool {
+    self.is_in_use() || self.creation_time.elapsed() < self.ttl
+  }
+}
+
+/// Keeps one session per CP group for the session based data structures,
+/// like `FencedLockProxy`. Sessions that are in use are kept alive with
+/// periodic heartbeats, the members close the others once their time to live
+/// passed.
+pub struct CpSessionManager {
+  endpoint_name: String,
+  invocation_service: Arc<InvocationService>,
+  connection_registry: Arc<ConnectionRegistry>,
+  sessions: Mutex<HashMap<RaftGroupId, SessionState>>,
+  thread_ids: Mutex<HashMap<(RaftGroupId, i64), i64>>,
+  heartbeat_task: Mutex<Option<JoinHandle<()>>>,
+  running: RwLock<bool>,
+}
+
+impl CpSessionManager {
+  pub fn new(endpoint_name: String, invocation_service: Arc<InvocationService>, connection_registry: Arc<ConnectionRegistry>) -> Self {
+    Self {
+      endpoint_name,
+      invocation_service,
+      connection_registry,
+      sessions: Mutex::new(HashMap::new()),
+      thread_ids: Mutex::new(HashMap::new()),
+      heartbeat_task: Mutex::new(None),
+      running: RwLock::new(true),
+    }
+  }
+
+  /// Returns `NO_SESSION_ID` if there is no session on the group.
+  pub async fn get_session(&self, group_id: &RaftGroupId) -> i64 {
+    match self.sessions.lock().await.get(group_id) {
+      Some(session) => session.id,
+      None => NO_SESSION_ID,
+    }
+  }
+
+  /// Returns the session on the group, creating it if there is none or the
+  /// existing one expired. Every call has to be paired with `release_session`.
+  pub async fn acquire_session(self: &Arc<Self>, group_id: &RaftGroupId) -> Result<i64, FencedLockError> {
+    if !*self.running.read().await {
+      return Err(FencedLockError::ShutDown);
+    }
+    if let Some(session_id) = self.acquire_valid_session(group_id).await {
+      return Ok(session_id);
+    }
+
+    let response = self.create_session(group_id).await;
+    let mut sessions = self.sessions.lock().await;
+    if let Some(session) = sessions.get_mut(group_id) {
+      if session.is_valid() {
+        // Another task created a session in the meantime, the new one is
+        // dropped by the members once its time to live passed.
+        session.acquire_count += 1;
+        return Ok(session.id);
+      }
+    }
+    sessions.insert(group_id.clone(), SessionState {
+      id: response.session_id,
+      ttl: Duration::from_millis(response.ttl_millis as u64),
+      creation_time: Instant::now(),
+      acquire_count: 1,
+    });
+    drop(sessions);
+    self.schedule_heartbeat_task(Duration::from_millis(response.heartbeat_millis as u64)).await;
+    Ok(response.session_id)
+  }
+
+  async fn acquire_valid_session(&self, group_id: &RaftGroupId) -> Option<i64> {
+    let mut sessions = self.sessions.lock().await;
+    let session = sessions.get_mut(group_id).filter(|session| session.is_valid())?;
+    session.acquire_count += 1;
+    Some(session.id)
+  }
+
+  pub async fn release_session(&self, group_id: &RaftGroupId, session_id: i64) {
+    if let Some(session) = self.sessions.lock().await.get_mut(group_id) {
+      if session.id == session_id {
+        session.acquire_count -= 1;
+      }
+    }
+  }
+
+  /// Forgets the session, e.g. after the members reported it as closed.
+  pub async fn invalidate_session(&self, group_id: &RaftGroupId, session_id: i64) {
+    let mut sessions = self.sessions.lock().await;
+    if sessions.get(group_id).map(|session| session.id) == Some(session_id) {
+      sessions.remove(group_id);
+    }
+  }
+
+  /// Id the members know the enclosing `ThreadId::scope` by. The members hand
+  /// out these ids so they are unique across all clients.
+  pub async fn get_or_create_unique_thread_id(&self, group_id: &RaftGroupId) -> Result<i64, MissingThreadIdScopeError> {
+    let key = (group_id.clone(), ThreadId::scoped()?);
+    let mut thread_ids = self.thread_ids.lock().await;
+    if let Some(thread_id) = thread_ids.get(&key) {
+      return Ok(*thread_id);
+    }
+    let request = CpSessionGenerateThreadIdCodec::encode_request(group_id).await;
+    let thread_id = self.invoke(
+      request,
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(CpSessionGenerateThreadIdCodec::decode_response(&mut response).await)) })),
+    ).await;
+    thread_ids.insert(key, thread_id);
+    Ok(thread_id)
+  }
+
+  /// Stops the heartbeats and closes all sessions on the members, which
+  /// releases the locks they hold.
+  pub async fn shutdown(&self) {
+    *self.running.write().await = false;
+    if let Some(heartbeat_task) = self.heartbeat_task.lock().await.take() {
+      heartbeat_task.abort();
+    }
+    let sessions: Vec<(RaftGroupId, i64)> = self.sessions.lock().await.drain()
+      .map(|(group_id, session)| (group_id, session.id))
+      .collect();
+    join_all(sessions.iter().map(|(group_id, session_id)| self.close_session(group_id, *session_id))).await;
+    self.thread_ids.lock().await.clear();
+  }
+
+  async fn schedule_heartbeat_task(self: &Arc<Self>, heartbeat_interval: Duration) {
+    let mut heartbeat_task = self.heartbeat_task.lock().await;
+    if heartbeat_task.is_some() {
+      return;
+    }
+    let this = self.clone();
+    *heartbeat_task = Some(tokio::spawn(async move {
+      loop {
+        tokio::time::sleep(heartbeat_interval).await;
+        let sessions: Vec<(RaftGroupId, i64)> = this.sessions.lock().await.iter()
+          .filter(|(_, session)| session.is_in_use())
+          .map(|(group_id, session)| (group_id.clone(), session.id))
+          .collect();
+        join_all(sessions.iter().map(|(group_id, session_id)| this.heartbeat(group_id, *session_id))).await;
+      }
+    }));
+  }
+
+  async fn create_session(&self, group_id: &RaftGroupId) -> CpSessionCreateSessionResponseParams {
+    let request = CpSessionCreateSessionCodec::encode_request(group_id, &self.endpoint_name).await;
+    self.invoke(
+      request,
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(CpSessionCreateSessionCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn heartbeat(&self, group_id: &RaftGroupId, session_id: i64) {
+    let request = CpSessionHeartbeatSessionCodec::encode_request(group_id, &session_id).await;
+    let mut invocation = Invocation::<Box<Box<()>>>::new(self.invocation_service.clone(), request);
+    invocation.handler = Some(Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })));
+    if let Err(error) = self.invocation_service.try_invoke(&self.connection_registry, invocation).await {
+      if is_session_expired(&error) {
+        self.invalidate_session(group_id, session_id).await;
+      }
+    }
+  }
+
+  async fn close_session(&self, group_id: &RaftGroupId, session_id: i64) -> bool {
+    let request = CpSessionCloseSessionCodec::encode_request(group_id, &session_id).await;
+    self.invoke(
+      request,
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(CpSessionCloseSessionCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn invoke<R: InvocationReturnValue + Clone>(&self, request: ClientMessage, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> R {
+    let mut invocation = Invocation::<Box<Box<R>>>::new(self.invocation_service.clone(), request);
+    invocation.handler = Some(decoder);
+    self.invocation_service.invoke(&self.connection_registry, invocation).await
+  }
+}
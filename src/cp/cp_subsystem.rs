@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use crate::cp::atomic_long_proxy::AtomicLongProxy;
+use crate::cp::atomic_reference_proxy::AtomicReferenceProxy;
+use crate::cp::cp_proxy_manager::{CpProxyManager, InvalidCpNameError};
+use crate::cp::cp_session_manager::CpSessionManager;
+use crate::cp::fenced_lock_proxy::FencedLockProxy;
+use crate::lifecycle_service::LifecycleService;
+use crate::proxy::manager::ProxyManager;
+use crate::serialization::serializable::Serializable;
+
+/// Entry point to the data structures of the CP subsystem. Names of the form
+/// `name@group` put the object on the CP group `group`, which is created on
+/// first use.
+pub struct CpSubsystem {
+  cp_proxy_manager: CpProxyManager,
+  session_manager: Arc<CpSessionManager>,
+}
+
+impl CpSubsystem {
+  /// Sessions are closed when `lifecycle_service` shuts down.
+  pub async fn new(proxy_manager: Arc<ProxyManager>, lifecycle_service: Arc<LifecycleService>) -> Self {
+    let session_manager = Arc::new(CpSessionManager::new(
+      proxy_manager.config.client_name.clone(),
+      proxy_manager.invocation_service.clone(),
+      proxy_manager.connection_registry.clone(),
+    ));
+    lifecycle_service.add_shutdown_hook({
+      let session_manager = session_manager.clone();
+      move || {
+        let session_manager = session_manager.clone();
+        Box::pin(async move { session_manager.shutdown().await })
+      }
+    }).await;
+    Self {
+      cp_proxy_manager: CpProxyManager::new(proxy_manager, session_manager.clone()),
+      session_manager,
+    }
+  }
+
+  pub async fn get_atomic_long(&self, name: impl ToString) -> Result<AtomicLongProxy, InvalidCpNameError> {
+    self.cp_proxy_manager.get_or_create_proxy(name).await
+  }
+
+  pub async fn get_atomic_reference<T>(&self, name: impl ToString) -> Result<AtomicReferenceProxy<T>, InvalidCpNameError>
+  where
+    T: Clone + Send + Sync + Serializable + 'static,
+  {
+    self.cp_proxy_manager.get_or_create_proxy(name).await
+  }
+
+  pub async fn get_lock(&self, name: impl ToString) -> Result<FencedLockProxy, InvalidCpNameError> {
+    self.cp_proxy_manager.get_or_create_fenced_lock(name).await
+  }
+
+  pub fn get_session_manager(&self) -> &Arc<CpSessionManager> {
+    &self.session_manager
+  }
+}
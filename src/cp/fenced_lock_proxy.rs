@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::codec::fenced_lock_get_lock_ownership_codec::{FencedLockGetLockOwnershipCodec, FencedLockGetLockOwnershipResponseParams};
+use crate::codec::fenced_lock_lock_codec::FencedLockLockCodec;
+use crate::codec::fenced_lock_try_lock_codec::FencedLockTryLockCodec;
+use crate::codec::fenced_lock_unlock_codec::FencedLockUnlockCodec;
+use crate::cp::cp_session_manager::{is_session_expired, CpSessionManager, NO_SESSION_ID};
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::invocation::InvocationError;
+use crate::proxy::base::ProxyBase;
+use crate::util::thread_id::MissingThreadIdScopeError;
+
+/// Fence the members answer with if the lock was not acquired.
+pub const INVALID_FENCE: i64 = 0;
+
+#[derive(Debug, Clone)]
+pub enum FencedLockError {
+  /// The lock is configured with a reentrancy limit that the current thread
+  /// already reached.
+  AcquireLimitReached { name: String },
+  NotOwner { name: String },
+  /// The members closed the session the current thread held the lock with,
+  /// which released the lock.
+  OwnershipLost { name: String, session_id: i64 },
+  ShutDown,
+  MissingThreadIdScope,
+  Invocation(InvocationError),
+}
+
+impl Display for FencedLockError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      FencedLockError::AcquireLimitReached { name } => write!(f, "lock acquire limit of {} is reached", name),
+      FencedLockError::NotOwner { name } => write!(f, "current thread is not the owner of lock {}", name),
+      FencedLockError::OwnershipLost { name, session_id } => write!(f, "current thread lost lock {} because its session {} was closed by the members", name, session_id),
+      FencedLockError::ShutDown => write!(f, "session manager is already shut down"),
+      FencedLockError::MissingThreadIdScope => write!(f, "{}", MissingThreadIdScopeError),
+      FencedLockError::Invocation(error) => write!(f, "{}", error),
+    }
+  }
+}
+
+impl std::error::Error for FencedLockError {}
+
+impl From<MissingThreadIdScopeError> for FencedLockError {
+  fn from(_: MissingThreadIdScopeError) -> Self {
+    FencedLockError::MissingThreadIdScope
+  }
+}
+
+/// A reentrant lock of the CP subsystem. The lock is owned by the current
+/// `ThreadId` within the CP session of this client, so it is released by the
+/// members if the client stops heartbeating. Every successful acquire returns
+/// a fence, a number that increases monotonically with each new owner and can
+/// be used to reject requests of owners that lost the lock in the meantime.
+///
+/// Tasks are told apart by their [`ThreadId::scope`](crate::util::thread_id::ThreadId::scope),
+/// so every task that uses the lock has to run inside its own scope. Outside
+/// of one all operations fail with `FencedLockError::MissingThreadIdScope`.
+#[derive(Clone)]
+pub struct FencedLockProxy {
+  base: ProxyBase,
+  group_id: RaftGroupId,
+  proxy_name: String,
+  session_manager: Arc<CpSessionManager>,
+  locked_session_ids: Arc<Mutex<HashMap<i64, i64>>>,
+}
+
+impl FencedLockProxy {
+  pub const SERVICE_NAME: &'static str = "hz:raft:lockService";
+
+  pub fn new(
+    base: ProxyBase,
+    group_id: RaftGroupId,
+    proxy_name: String,
+    session_manager: Arc<CpSessionManager>,
+  ) -> Self {
+    FencedLockProxy {
+      base,
+      group_id,
+      proxy_name,
+      session_manager,
+      locked_session_ids: Arc::new(Mutex::new(HashMap::new())),
+    }
+  }
+
+  /// The name the proxy was requested with, without a `@default` suffix.
+  pub fn get_name(&self) -> &str {
+    &self.proxy_name
+  }
+
+  pub fn get_group_id(&self) -> &RaftGroupId {
+    &self.group_id
+  }
+
+  /// Waits until the lock is acquired.
+  pub async fn lock(&self) -> Result<(), FencedLockError> {
+    self.lock_and_get_fence().await?;
+    Ok(())
+  }
+
+  /// Retries with a new session if the members closed the current one, unless
+  /// the current thread held the lock with it.
+  pub async fn lock_and_get_fence(&self) -> Result<i64, FencedLockError> {
+    let thread_id = self.get_thread_id().await?;
+    let invocation_uid = Uuid::new_v4();
+    loop {
+      let session_id = self.session_manager.acquire_session(&self.group_id).await?;
+      self.verify_locked_session_id_if_present(thread_id, session_id, true).await?;
+      let group_id = self.group_id.clone();
+      let fence = self.base.try_encode_invoke(
+        Box::pin({
+          move |name| Box::pin({
+            let group_id = group_id.clone();
+            async move {
+              FencedLockLockCodec::encode_request(&group_id, &name, &session_id, &thread_id, &invocation_uid).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(FencedLockLockCodec::decode_response(&mut response).await)) })),
+      ).await;
+      let fence = match fence {
+        Ok(fence) => fence,
+        Err(error) => {
+          self.handle_acquire_error(thread_id, session_id, error).await?;
+          continue;
+        }
+      };
+      if fence == INVALID_FENCE {
+        self.session_manager.release_session(&self.group_id, session_id).await;
+        return Err(FencedLockError::AcquireLimitReached { name: self.proxy_name.clone() });
+      }
+      self.locked_session_ids.lock().await.insert(thread_id, session_id);
+      return Ok(fence);
+    }
+  }
+
+  /// Returns `false` if the lock could not be acquired within `timeout`.
+  pub async fn try_lock(&self, timeout: Duration) -> Result<bool, FencedLockError> {
+    Ok(self.try_lock_and_get_fence(timeout).await? != INVALID_FENCE)
+  }
+
+  /// Returns `INVALID_FENCE` if the lock could not be acquired within
+  /// `timeout`.
+  pub async fn try_lock_and_get_fence(&self, timeout: Duration) -> Result<i64, FencedLockError> {
+    let thread_id = self.get_thread_id().await?;
+    let invocation_uid = Uuid::new_v4();
+    let start = Instant::now();
+    loop {
+      let timeout_ms = timeout.saturating_sub(start.elapsed()).as_millis() as i64;
+      let session_id = self.session_manager.acquire_session(&self.group_id).await?;
+      self.verify_locked_session_id_if_present(thread_id, session_id, true).await?;
+      let group_id = self.group_id.clone();
+      let fence = self.base.try_encode_invoke(
+        Box::pin({
+          move |name| Box::pin({
+            let group_id = group_id.clone();
+            async move {
+              FencedLockTryLockCodec::encode_request(&group_id, &name, &session_id, &thread_id, &invocation_uid, &timeout_ms).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(FencedLockTryLockCodec::decode_response(&mut response).await)) })),
+      ).await;
+      let fence = match fence {
+        Ok(fence) => fence,
+        Err(error) => {
+          self.handle_acquire_error(thread_id, session_id, error).await?;
+          if start.elapsed() >= timeout {
+            return Ok(INVALID_FENCE);
+          }
+          continue;
+        }
+      };
+      if fence == INVALID_FENCE {
+        self.session_manager.release_session(&self.group_id, session_id).await;
+      } else {
+        self.locked_session_ids.lock().await.insert(thread_id, session_id);
+      }
+      return Ok(fence);
+    }
+  }
+
+  /// Releases one hold of the current thread.
+  pub async fn unlock(&self) -> Result<(), FencedLockError> {
+    let thread_id = self.get_thread_id().await?;
+    let session_id = self.session_manager.get_session(&self.group_id).await;
+    self.verify_locked_session_id_if_present(thread_id, session_id, false).await?;
+    if session_id == NO_SESSION_ID {
+      self.locked_session_ids.lock().await.remove(&thread_id);
+      return Err(FencedLockError::NotOwner { name: self.proxy_name.clone() });
+    }
+
+    let invocation_uid = Uuid::new_v4();
+    let group_id = self.group_id.clone();
+    let still_locked_by_current_thread = self.base.try_encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          async move {
+            FencedLockUnlockCodec::encode_request(&group_id, &name, &session_id, &thread_id, &invocation_uid).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(FencedLockUnlockCodec::decode_response(&mut response).await)) })),
+    ).await;
+    let still_locked_by_current_thread = match still_locked_by_current_thread {
+      Ok(still_locked_by_current_thread) => still_locked_by_current_thread,
+      Err(error) if is_session_expired(&error) => {
+        self.session_manager.invalidate_session(&self.group_id, session_id).await;
+        self.locked_session_ids.lock().await.remove(&thread_id);
+        return Err(FencedLockError::OwnershipLost { name: self.proxy_name.clone(), session_id });
+      }
+      Err(error) => return Err(FencedLockError::Invocation(error)),
+    };
+    if still_locked_by_current_thread {
+      self.locked_session_ids.lock().await.insert(thread_id, session_id);
+    } else {
+      self.locked_session_ids.lock().await.remove(&thread_id);
+    }
+    self.session_manager.release_session(&self.group_id, session_id).await;
+    Ok(())
+  }
+
+  /// Fence of the current hold.
+  pub async fn get_fence(&self) -> Result<i64, FencedLockError> {
+    let thread_id = self.get_thread_id().await?;
+    let session_id = self.session_manager.get_session(&self.group_id).await;
+    self.verify_locked_session_id_if_present(thread_id, session_id, false).await?;
+    if session_id == NO_SESSION_ID {
+      self.locked_session_ids.lock().await.remove(&thread_id);
+      return Err(FencedLockError::NotOwner { name: self.proxy_name.clone() });
+    }
+
+    let ownership = self.get_lock_ownership().await;
+    if Self::is_locked_by(&ownership, session_id, thread_id) {
+      self.locked_session_ids.lock().await.insert(thread_id, session_id);
+      return Ok(ownership.fence);
+    }
+    self.verify_no_locked_session_id_present(thread_id).await?;
+    Err(FencedLockError::NotOwner { name: self.proxy_name.clone() })
+  }
+
+  pub async fn is_locked(&self) -> Result<bool, FencedLockError> {
+    let ownership = self.get_verified_lock_ownership().await?;
+    Ok(ownership.fence != INVALID_FENCE)
+  }
+
+  pub async fn is_locked_by_current_thread(&self) -> Result<bool, FencedLockError> {
+    let thread_id = self.get_thread_id().await?;
+    let session_id = self.session_manager.get_session(&self.group_id).await;
+    let ownership = self.get_verified_lock_ownership().await?;
+    Ok(Self::is_locked_by(&ownership, session_id, thread_id))
+  }
+
+  /// Number of holds of the current owner, `0` if the lock is free.
+  pub async fn get_lock_count(&self) -> Result<i32, FencedLockError> {
+    let ownership = self.get_verified_lock_ownership().await?;
+    Ok(ownership.lock_count)
+  }
+
+  /// Fetches the ownership and checks that a lock the current thread held
+  /// was not lost together with its session.
+  async fn get_verified_lock_ownership(&self) -> Result<FencedLockGetLockOwnershipResponseParams, FencedLockError> {
+    let thread_id = self.get_thread_id().await?;
+    let session_id = self.session_manager.get_session(&self.group_id).await;
+    self.verify_locked_session_id_if_present(thread_id, session_id, false).await?;
+
+    let ownership = self.get_lock_ownership().await;
+    if Self::is_locked_by(&ownership, session_id, thread_id) {
+      self.locked_session_ids.lock().await.insert(thread_id, session_id);
+    } else {
+      self.verify_no_locked_session_id_present(thread_id).await?;
+    }
+    Ok(ownership)
+  }
+
+  async fn get_lock_ownership(&self) -> FencedLockGetLockOwnershipResponseParams {
+    let group_id = self.group_id.clone();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let group_id = group_id.clone();
+          async move {
+            FencedLockGetLockOwnershipCodec::encode_request(&group_id, &name).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(FencedLockGetLockOwnershipCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn get_thread_id(&self) -> Result<i64, FencedLockError> {
+    Ok(self.session_manager.get_or_create_unique_thread_id(&self.group_id).await?)
+  }
+
+  /// A closed session is dropped so the next attempt creates a new one, every
+  /// other error ends the attempt.
+  async fn handle_acquire_error(&self, thread_id: i64, session_id: i64, error: InvocationError) -> Result<(), FencedLockError> {
+    if is_session_expired(&error) {
+      self.session_manager.invalidate_session(&self.group_id, session_id).await;
+      self.verify_no_locked_session_id_present(thread_id).await
+    } else {
+      self.session_manager.release_session(&self.group_id, session_id).await;
+      Err(FencedLockError::Invocation(error))
+    }
+  }
+
+  fn is_locked_by(ownership: &FencedLockGetLockOwnershipResponseParams, session_id: i64, thread_id: i64) -> bool {
+    ownership.fence != INVALID_FENCE && ownership.session_id == session_id && ownership.thread_id == thread_id
+  }
+
+  /// The members release the locks of a closed session, so a lock held with
+  /// another session than the current one is lost.
+  async fn verify_locked_session_id_if_present(&self, thread_id: i64, session_id: i64, release_session: bool) -> Result<(), FencedLockError> {
+    let mut locked_session_ids = self.locked_session_ids.lock().await;
+    if let Some(locked_session_id) = locked_session_ids.get(&thread_id).cloned() {
+      if locked_session_id != session_id {
+        locked_session_ids.remove(&thread_id);
+        drop(locked_session_ids);
+        if release_session {
+          self.session_manager.release_session(&self.group_id, session_id).await;
+        }
+        return Err(FencedLockError::OwnershipLost { name: self.proxy_name.clone(), session_id: locked_session_id });
+      }
+    }
+    Ok(())
+  }
+
+  async fn verify_no_locked_session_id_present(&self, thread_id: i64) -> Result<(), FencedLockError> {
+    match self.locked_session_ids.lock().await.remove(&thread_id) {
+      Some(locked_session_id) => Err(FencedLockError::OwnershipLost { name: self.proxy_name.clone(), session_id: locked_session_id }),
+      None => Ok(()),
+    }
+  }
+}
@@ -0,0 +1,9 @@
+use crate::serialization::serializable::Serializable;
+
+/// Logic that the CP group runs on the current value of an atomic data
+/// structure, serialized like a
+/// [`ServerSideFunction`](crate::proxy::server_side_function::ServerSideFunction).
+/// `Output` is the type the members answer with.
+pub trait Function: Serializable + Send + Sync + 'static {
+  type Output: Send + Sync + Clone + 'static;
+}
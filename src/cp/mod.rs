@@ -0,0 +1,18 @@
+use crate::cp::raft_group_id::RaftGroupId;
+use crate::proxy::base::ProxyBase;
+
+pub mod atomic_long_proxy;
+pub mod atomic_reference_proxy;
+pub mod cp_proxy_manager;
+pub mod cp_session_manager;
+pub mod cp_subsystem;
+pub mod fenced_lock_proxy;
+pub mod function;
+pub mod raft_group_id;
+
+/// A data structure of the CP subsystem. Unlike `Proxy`, it is not created on
+/// the members up front but lives on the CP group it was resolved to.
+pub trait CpProxy: Sized {
+  const SERVICE_NAME: &'static str;
+  fn create_proxy(base: ProxyBase, group_id: RaftGroupId, proxy_name: String) -> Self;
+}
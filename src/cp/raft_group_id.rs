@@ -0,0 +1,18 @@
+/// Identifies the CP group, i.e. the set of CP members running a Raft
+/// consensus, that a CP data structure lives on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RaftGroupId {
+  pub name: String,
+  pub seed: i64,
+  pub id: i64,
+}
+
+impl RaftGroupId {
+  pub fn new(name: String, seed: i64, id: i64) -> Self {
+    Self {
+      name,
+      seed,
+      id,
+    }
+  }
+}
@@ -1,8 +1,8 @@
 use uuid::Uuid;
 
 pub struct ConnectionRegistration {
-    server_registration_id: Uuid,
-    correlation_id: u64
+    pub server_registration_id: Uuid,
+    pub correlation_id: u64
 }
 
 impl ConnectionRegistration {
@@ -1,3 +1,4 @@
+use std::fmt::{Display, Formatter};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -17,6 +18,25 @@ pub trait InvocationReturnValue: Send + Sync {}
 
 impl<T: Send + Sync> InvocationReturnValue for T {}
 
+/// Why an invocation failed. `class_name` is the class of the exception the
+/// member answered with, `None` if the request never made it to a member.
+#[derive(Debug, Clone)]
+pub struct InvocationError {
+  pub class_name: Option<String>,
+  pub message: String,
+}
+
+impl Display for InvocationError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match &self.class_name {
+      Some(class_name) => write!(f, "{}: {}", class_name, self.message),
+      None => write!(f, "{}", self.message),
+    }
+  }
+}
+
+impl std::error::Error for InvocationError {}
+
 pub struct Invocation<R: InvocationReturnValue + Send + Sync> {
   pub invocation_service: Arc<InvocationService>,
   pub request: ClientMessage,
@@ -31,7 +51,7 @@ pub struct Invocation<R: InvocationReturnValue + Send + Sync> {
   pub pending_response_received: NaiveDateTime,
   pub invoke_count: i32,
   pub urgent: bool,
-  pub deferred: Option<DeferredFuture<R, String>>,
+  pub deferred: Option<DeferredFuture<R, InvocationError>>,
   pub handler: Option<Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=R>>>>>>,
   pub event_handler: Option<Arc<Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=()>>>>>>>,
 }
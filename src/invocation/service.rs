@@ -13,7 +13,7 @@ use crate::ClientConfig;
 use crate::codec_builtin::error_codec::ErrorCodec;
 use crate::connection::registry::ConnectionRegistry;
 use crate::core::distributed_object::DistributedObject;
-use crate::invocation::{Invocation, InvocationReturnValue};
+use crate::invocation::{Invocation, InvocationError, InvocationReturnValue};
 use crate::network::connection::Connection;
 use crate::protocol::client_message::ClientMessage;
 use crate::proxy::map_proxy::AnySend;
@@ -43,7 +43,13 @@ impl InvocationService {
     self.invoke(connection_registry, invocation).await
   }
 
-  pub async fn invoke<R: InvocationReturnValue + Clone>(&self, connection_registry: &ConnectionRegistry, mut invocation: Invocation<Box<Box<R>>>) -> R {
+  pub async fn invoke<R: InvocationReturnValue + Clone>(&self, connection_registry: &ConnectionRegistry, invocation: Invocation<Box<Box<R>>>) -> R {
+    self.try_invoke(connection_registry, invocation).await.unwrap()
+  }
+
+  /// Like `invoke`, but hands the error the member answered with to the
+  /// caller instead of panicking.
+  pub async fn try_invoke<R: InvocationReturnValue + Clone>(&self, connection_registry: &ConnectionRegistry, mut invocation: Invocation<Box<Box<R>>>) -> Result<R, InvocationError> {
     invocation.deferred = Some(DeferredFuture::default());
     let mut correllation_counter = self.correllation_counter.write().await;
     *correllation_counter += 1;
@@ -51,7 +57,7 @@ impl InvocationService {
     let deferred = invocation.deferred.clone();
     let invocation = Arc::new(RwLock::new(invocation));
     self.do_invoke(connection_registry, invocation).await;
-    **deferred.unwrap().wait().await.unwrap()
+    deferred.unwrap().wait().await.map(|response| **response)
   }
 
   pub async fn do_invoke<R: InvocationReturnValue + Clone>(&self, connection_registry: &ConnectionRegistry, invocation: Arc<RwLock<Invocation<Box<Box<R>>>>>) {
@@ -67,6 +73,14 @@ impl InvocationService {
     self.invoke(connection_registry, invocation).await
   }
 
+  pub async fn try_invoke_on_partition<R: InvocationReturnValue + Send + Sync + Clone>(self: &Arc<Self>, connection_registry: &ConnectionRegistry, request: ClientMessage, partition_id: i32, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> Result<R, InvocationError> {
+    let mut invocation = Invocation::<Box<Box<R>>>::new(self.clone(), request);
+    invocation.partition_id = partition_id;
+    invocation.handler = Some(decoder);
+
+    self.try_invoke(connection_registry, invocation).await
+  }
+
   pub async fn invoke_on_random_target<T: DistributedObject>(self: &Arc<Self>, connection_registry: &ConnectionRegistry, request: ClientMessage, handler: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<Arc<T>>>>>>>>) -> Box<Arc<T>> {
     let mut invocation = Invocation::<Box<Box<Arc<T>>>>::new(self.clone(), request);
     invocation.handler = Some(handler);
@@ -80,7 +94,7 @@ impl InvocationService {
       if !invocation.urgent {
         let error = connection_registry.check_if_invocation_allowed().await;
         if let Some(error) = error {
-          self.notify_error(&mut *invocation, error).await;
+          self.notify_error(&mut *invocation, InvocationError { class_name: None, message: error }).await;
           return;
         }
       }
@@ -131,7 +145,15 @@ impl InvocationService {
 
     let message_type = client_message.get_message_type().await;
     if message_type == ErrorCodec::EXCEPTION_MESSAGE_TYPE {
-      todo!()
+      let mut client_message = client_message;
+      let error = match ErrorCodec::decode(&mut client_message).await.into_iter().next() {
+        Some(error_holder) => InvocationError {
+          class_name: Some(error_holder.class_name),
+          message: error_holder.message.unwrap_or_default(),
+        },
+        None => InvocationError { class_name: None, message: "member answered with an empty error".to_string() },
+      };
+      self.notify_error(&mut *pending_invocation.unwrap().write().await, error).await;
     } else {
       pending_invocation.unwrap().write().await.notify(client_message).await;
     }
@@ -161,6 +183,10 @@ impl InvocationService {
     self.invocations.write().await.remove(&correlation_id);
   }
 
+  pub async fn deregister_event_handler(&self, correlation_id: u64) {
+    self.invocations_with_event_handlers.write().await.remove(&correlation_id);
+  }
+
   pub async fn register_invocation<R: InvocationReturnValue + Clone>(&self, invocation: Arc<RwLock<Invocation<Box<Box<R>>>>>) {
     let correlation_id = {
       let mut invocation = invocation.write().await;
@@ -182,7 +208,7 @@ impl InvocationService {
     invocations.insert(correlation_id, unsafe { transmute(invocation) });
   }
 
-  pub async fn notify_error<R: InvocationReturnValue + Clone>(&self, invocation: &mut Invocation<Box<R>>, error: String) {
+  pub async fn notify_error<R: InvocationReturnValue>(&self, invocation: &mut Invocation<Box<R>>, error: InvocationError) {
     std::mem::replace(&mut invocation.deferred, None).unwrap().reject(error).await;
   }
 }
\ No newline at end of file
@@ -7,7 +7,6 @@ use crate::client::HazelcastClient;
 use crate::config::network::ClientNetworkConfig;
 use crate::config::ClientConfig;
 use crate::connection::address::provider::DefaultAddressProvider;
-use crate::proxy::event_type::EventType;
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::sync::Arc;
 use std::time::Duration;
@@ -19,6 +18,7 @@ pub mod codec_builtin;
 pub mod config;
 pub mod connection;
 pub mod core;
+pub mod cp;
 pub mod invocation;
 pub mod lifecycle_service;
 pub mod listener;
@@ -31,29 +31,229 @@ pub mod serialization;
 pub mod util;
 
 pub mod codec {
+    pub mod atomic_long_add_and_get_codec;
+    pub mod atomic_long_alter_codec;
+    pub mod atomic_long_apply_codec;
+    pub mod atomic_long_compare_and_set_codec;
+    pub mod atomic_long_get_codec;
+    pub mod atomic_long_get_and_add_codec;
+    pub mod atomic_long_get_and_set_codec;
+
+    pub mod atomic_ref_apply_codec;
+    pub mod atomic_ref_compare_and_set_codec;
+    pub mod atomic_ref_contains_codec;
+    pub mod atomic_ref_get_codec;
+    pub mod atomic_ref_set_codec;
+
     pub mod client_add_cluster_view_listener_codec;
     pub mod client_authentication_codec;
     pub mod client_create_proxy_codec;
     pub mod client_fetch_schema_codec;
 
+    pub mod cp_group_create_cp_group_codec;
+
+    pub mod cp_session_close_session_codec;
+    pub mod cp_session_create_session_codec;
+    pub mod cp_session_generate_thread_id_codec;
+    pub mod cp_session_heartbeat_session_codec;
+
+    pub mod fenced_lock_get_lock_ownership_codec;
+    pub mod fenced_lock_lock_codec;
+    pub mod fenced_lock_try_lock_codec;
+    pub mod fenced_lock_unlock_codec;
+
+    pub mod list_add_all_codec;
+    pub mod list_add_all_with_index_codec;
+    pub mod list_add_codec;
+    pub mod list_add_listener_codec;
+    pub mod list_add_with_index_codec;
+    pub mod list_clear_codec;
+    pub mod list_compare_and_remove_all_codec;
+    pub mod list_compare_and_retain_all_codec;
+    pub mod list_contains_all_codec;
+    pub mod list_contains_codec;
+    pub mod list_get_all_codec;
+    pub mod list_get_codec;
+    pub mod list_index_of_codec;
+    pub mod list_is_empty_codec;
+    pub mod list_last_index_of_codec;
+    pub mod list_remove_codec;
+    pub mod list_remove_listener_codec;
+    pub mod list_remove_with_index_codec;
+    pub mod list_set_codec;
+    pub mod list_size_codec;
+    pub mod list_sub_codec;
+
     pub mod map_add_entry_listener_codec;
+    pub mod map_add_entry_listener_to_key_codec;
+    pub mod map_add_entry_listener_to_key_with_predicate_codec;
+    pub mod map_add_entry_listener_with_predicate_codec;
+    pub mod map_add_index_codec;
+    pub mod map_add_interceptor_codec;
+    pub mod map_aggregate_codec;
+    pub mod map_aggregate_with_predicate_codec;
+    pub mod map_clear_codec;
+    pub mod map_contains_key_codec;
+    pub mod map_contains_value_codec;
+    pub mod map_delete_codec;
+    pub mod map_entries_with_paging_predicate_codec;
+    pub mod map_entries_with_predicate_codec;
+    pub mod map_entry_set_codec;
+    pub mod map_event_journal_read_codec;
+    pub mod map_event_journal_subscribe_codec;
+    pub mod map_evict_all_codec;
+    pub mod map_evict_codec;
+    pub mod map_execute_on_all_keys_codec;
+    pub mod map_execute_on_key_codec;
+    pub mod map_execute_on_keys_codec;
+    pub mod map_execute_with_predicate_codec;
+    pub mod map_fetch_entries_codec;
+    pub mod map_fetch_keys_codec;
+    pub mod map_flush_codec;
+    pub mod map_force_unlock_codec;
+    pub mod map_get_all_codec;
     pub mod map_get_codec;
+    pub mod map_get_entry_view_codec;
+    pub mod map_is_empty_codec;
+    pub mod map_is_locked_codec;
+    pub mod map_key_set_codec;
+    pub mod map_key_set_with_paging_predicate_codec;
+    pub mod map_key_set_with_predicate_codec;
+    pub mod map_load_all_codec;
+    pub mod map_load_given_keys_codec;
+    pub mod map_lock_codec;
+    pub mod map_project_codec;
+    pub mod map_project_with_predicate_codec;
+    pub mod map_put_all_codec;
     pub mod map_put_codec;
+    pub mod map_put_if_absent_codec;
+    pub mod map_put_if_absent_with_max_idle_codec;
+    pub mod map_put_transient_codec;
+    pub mod map_put_transient_with_max_idle_codec;
+    pub mod map_put_with_max_idle_codec;
+    pub mod map_remove_all_codec;
     pub mod map_remove_codec;
     pub mod map_remove_entry_listener_codec;
+    pub mod map_remove_if_same_codec;
+    pub mod map_remove_interceptor_codec;
+    pub mod map_replace_codec;
+    pub mod map_replace_if_same_codec;
+    pub mod map_set_codec;
+    pub mod map_set_ttl_codec;
+    pub mod map_set_with_max_idle_codec;
+    pub mod map_size_codec;
+    pub mod map_submit_to_key_codec;
+    pub mod map_try_put_codec;
+    pub mod map_try_lock_codec;
+    pub mod map_try_remove_codec;
+    pub mod map_unlock_codec;
+    pub mod map_values_codec;
+    pub mod map_values_with_paging_predicate_codec;
+    pub mod map_values_with_predicate_codec;
 
     pub mod multi_map_add_entry_listener_codec;
+    pub mod multi_map_add_entry_listener_to_key_codec;
+    pub mod multi_map_clear_codec;
+    pub mod multi_map_contains_entry_codec;
+    pub mod multi_map_contains_key_codec;
+    pub mod multi_map_contains_value_codec;
+    pub mod multi_map_delete_codec;
+    pub mod multi_map_entry_set_codec;
+    pub mod multi_map_force_unlock_codec;
     pub mod multi_map_get_codec;
+    pub mod multi_map_is_locked_codec;
+    pub mod multi_map_key_set_codec;
+    pub mod multi_map_lock_codec;
+    pub mod multi_map_put_all_codec;
     pub mod multi_map_put_codec;
+    pub mod multi_map_remove_codec;
     pub mod multi_map_remove_entry_codec;
+    pub mod multi_map_remove_entry_listener_codec;
+    pub mod multi_map_size_codec;
+    pub mod multi_map_try_lock_codec;
+    pub mod multi_map_unlock_codec;
+    pub mod multi_map_value_count_codec;
+    pub mod multi_map_values_codec;
+
+    pub mod queue_add_all_codec;
+    pub mod queue_add_listener_codec;
+    pub mod queue_clear_codec;
+    pub mod queue_contains_all_codec;
+    pub mod queue_contains_codec;
+    pub mod queue_drain_to_codec;
+    pub mod queue_drain_to_max_size_codec;
+    pub mod queue_is_empty_codec;
+    pub mod queue_iterator_codec;
+    pub mod queue_offer_codec;
+    pub mod queue_peek_codec;
+    pub mod queue_poll_codec;
+    pub mod queue_put_codec;
+    pub mod queue_remaining_capacity_codec;
+    pub mod queue_remove_codec;
+    pub mod queue_remove_listener_codec;
+    pub mod queue_size_codec;
+    pub mod queue_take_codec;
+
+    pub mod replicated_map_add_entry_listener_codec;
+    pub mod replicated_map_add_entry_listener_to_key_codec;
+    pub mod replicated_map_add_entry_listener_to_key_with_predicate_codec;
+    pub mod replicated_map_add_entry_listener_with_predicate_codec;
+    pub mod replicated_map_clear_codec;
+    pub mod replicated_map_contains_key_codec;
+    pub mod replicated_map_contains_value_codec;
+    pub mod replicated_map_entry_set_codec;
+    pub mod replicated_map_get_codec;
+    pub mod replicated_map_is_empty_codec;
+    pub mod replicated_map_key_set_codec;
+    pub mod replicated_map_put_all_codec;
+    pub mod replicated_map_put_codec;
+    pub mod replicated_map_remove_codec;
+    pub mod replicated_map_remove_entry_listener_codec;
+    pub mod replicated_map_size_codec;
+    pub mod replicated_map_values_codec;
+
+    pub mod ringbuffer_add_all_codec;
+    pub mod ringbuffer_add_codec;
+    pub mod ringbuffer_capacity_codec;
+    pub mod ringbuffer_head_sequence_codec;
+    pub mod ringbuffer_read_many_codec;
+    pub mod ringbuffer_read_one_codec;
+    pub mod ringbuffer_remaining_capacity_codec;
+    pub mod ringbuffer_size_codec;
+    pub mod ringbuffer_tail_sequence_codec;
+
+    pub mod set_add_all_codec;
+    pub mod set_add_codec;
+    pub mod set_add_listener_codec;
+    pub mod set_clear_codec;
+    pub mod set_compare_and_remove_all_codec;
+    pub mod set_compare_and_retain_all_codec;
+    pub mod set_contains_all_codec;
+    pub mod set_contains_codec;
+    pub mod set_get_all_codec;
+    pub mod set_is_empty_codec;
+    pub mod set_remove_codec;
+    pub mod set_remove_listener_codec;
+    pub mod set_size_codec;
+
+    pub mod topic_add_message_listener_codec;
+    pub mod topic_publish_all_codec;
+    pub mod topic_publish_codec;
+    pub mod topic_remove_message_listener_codec;
 
     pub mod custom {
         pub mod address_codec;
+        pub mod anchor_data_list_holder_codec;
+        pub mod bitmap_index_options_codec;
         pub mod distributed_object_info_codec;
         pub mod endpoint_qualifier_codec;
         pub mod field_descriptor_codec;
+        pub mod index_config_codec;
         pub mod member_info_codec;
         pub mod member_version_codec;
+        pub mod paging_predicate_holder_codec;
+        pub mod raft_group_id_codec;
         pub mod schema_codec;
+        pub mod simple_entry_view_codec;
     }
 }
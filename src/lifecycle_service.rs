@@ -1,11 +1,16 @@
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 use event_listener_primitives::Bag;
 use tokio::sync::RwLock;
 
+type ShutdownHook = Box<dyn Fn() -> Pin<Box<dyn Future<Output=()> + Send + Sync>> + Send + Sync>;
+
 pub enum LifecycleState {
   Starting,
   Started,
   ShuttingDown,
+  Shutdown,
   Connected,
   Disconnected,
   ChangedCluster
@@ -13,14 +18,16 @@ pub enum LifecycleState {
 
 pub struct LifecycleService {
   pub bag: Arc<RwLock<Bag<Arc<dyn Fn(&LifecycleState) + Send + Sync>, LifecycleState>>>,
-  pub active: Arc<RwLock<bool>>
+  pub active: Arc<RwLock<bool>>,
+  shutdown_hooks: RwLock<Vec<ShutdownHook>>,
 }
 
 impl LifecycleService {
   pub fn new() -> Self {
     LifecycleService {
       bag: Arc::new(RwLock::new(Bag::default())),
-      active: Arc::new(RwLock::new(false))
+      active: Arc::new(RwLock::new(false)),
+      shutdown_hooks: RwLock::new(Vec::new()),
     }
   }
 
@@ -30,6 +37,25 @@ impl LifecycleService {
     self.emit_lifecycle_event(LifecycleState::Started).await;
   }
 
+  /// Runs the shutdown hooks in the order they were added, between the
+  /// `ShuttingDown` and `Shutdown` events.
+  pub async fn shutdown(&self) {
+    if !self.is_running().await {
+      return;
+    }
+    self.emit_lifecycle_event(LifecycleState::ShuttingDown).await;
+    *self.active.write().await = false;
+    for hook in self.shutdown_hooks.read().await.iter() {
+      hook().await;
+    }
+    self.emit_lifecycle_event(LifecycleState::Shutdown).await;
+  }
+
+  /// Registers async cleanup, like closing sessions on the cluster, that
+  /// has to finish before the client is shut down.
+  pub async fn add_shutdown_hook(&self, hook: impl Fn() -> Pin<Box<dyn Future<Output=()> + Send + Sync>> + Send + Sync + 'static) {
+    self.shutdown_hooks.write().await.push(Box::new(hook));
+  }
 
   pub async fn emit_lifecycle_event(&self, state: LifecycleState) {
     //todo: log
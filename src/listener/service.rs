@@ -8,6 +8,7 @@ use tokio::task::LocalSet;
 use uuid::Uuid;
 use crate::connection::manager::ConnectionManager;
 use crate::invocation::{Invocation, InvocationReturnValue};
+use crate::invocation::connection_registration::ConnectionRegistration;
 use crate::invocation::listener_registration::ListenerRegistration;
 use crate::invocation::service::InvocationService;
 use crate::listener::message_codec::ListenerMessageCodec;
@@ -31,7 +32,7 @@ impl ListenerService {
     }
   }
 
-  pub async fn register_listener(self: &Arc<Self>, codec: impl ListenerMessageCodec + Send + Sync + 'static, handler: impl Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=()>>> + 'static) {
+  pub async fn register_listener(self: &Arc<Self>, codec: impl ListenerMessageCodec + Send + Sync + 'static, handler: impl Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=()>>> + 'static) -> Uuid {
     let user_registration_id = Uuid::new_v4();
 
     let listener_registration = Arc::new(ListenerRegistration::new(handler, codec));
@@ -48,15 +49,43 @@ impl ListenerService {
       });
     }
     set.await;
+    user_registration_id
+  }
+
+  /// Removes the listener from every member it was registered on. Returns
+  /// false if the registration id is unknown.
+  pub async fn deregister_listener(self: &Arc<Self>, user_registration_id: Uuid) -> bool {
+    let listener_registration = if let Some(listener_registration) = self.registrations.write().await.remove(&user_registration_id) {
+      listener_registration
+    } else {
+      return false;
+    };
+
+    let mut connection_registrations = listener_registration.connection_registrations.write().await;
+    let active_connections = self.connection_manager.connection_registry.get_connections().await;
+    for connection in active_connections.into_values() {
+      if let Some(connection_registration) = connection_registrations.remove(&connection.connection_id) {
+        self.invocation_service.deregister_event_handler(connection_registration.correlation_id).await;
+        let deregister_request = listener_registration.codec.encode_remove_request(&connection_registration.server_registration_id).await;
+        let mut invocation: Invocation<Box<Box<()>>> = Invocation::new(self.invocation_service.clone(), deregister_request);
+        invocation.handler = Some(Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })));
+        invocation.connection = Some(connection);
+        self.invocation_service.invoke_urgent(&self.connection_manager.connection_registry, invocation).await;
+      }
+    }
+    connection_registrations.clear();
+    true
   }
 
   async fn invoke(&self, listener_registration: Arc<ListenerRegistration>, connection: Connection, user_registration_id: Uuid) {
-    let connection_registrations = listener_registration.connection_registrations.write().await;
+    let mut connection_registrations = listener_registration.connection_registrations.write().await;
     if connection_registrations.contains_key(&connection.connection_id) {
       return;
     }
 
     let register_request = listener_registration.codec.encode_add_request(&self.is_smart_service).await;
+    // shares its frames with the request, so the correlation id assigned on invoke is visible here
+    let correlation_request = register_request.clone();
     //todo: Add logging
 
     let mut invocation: Invocation<Box<Box<Uuid>>> = Invocation::new(self.invocation_service.clone(), register_request);
@@ -70,9 +99,13 @@ impl ListenerService {
       })
     }));
     invocation.event_handler = Some(listener_registration.handler.clone());
+    let connection_id = connection.connection_id;
     invocation.connection = Some(connection);
 
-    let uuid = self.invocation_service.invoke_urgent(&self.connection_manager.connection_registry, invocation).await;
-    println!("ugh: {:?}", uuid);
+    let server_registration_id = self.invocation_service.invoke_urgent(&self.connection_manager.connection_registry, invocation).await;
+    connection_registrations.insert(
+      connection_id,
+      ConnectionRegistration::new(server_registration_id, correlation_request.get_correlation_id().await),
+    );
   }
 }
\ No newline at end of file
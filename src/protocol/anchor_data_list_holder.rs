@@ -0,0 +1,16 @@
+use crate::serialization::heap_data::HeapData;
+
+#[derive(Default, Clone)]
+pub struct AnchorDataListHolder {
+  pub anchor_page_list: Vec<i32>,
+  pub anchor_data_list: Vec<(HeapData, HeapData)>,
+}
+
+impl AnchorDataListHolder {
+  pub fn new(anchor_page_list: Vec<i32>, anchor_data_list: Vec<(HeapData, HeapData)>) -> Self {
+    Self {
+      anchor_page_list,
+      anchor_data_list,
+    }
+  }
+}
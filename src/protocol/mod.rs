@@ -1,4 +1,6 @@
 pub mod client_message;
 pub mod error_holder;
 pub mod stack_trace_element;
-pub mod authentication_status;
\ No newline at end of file
+pub mod authentication_status;
+pub mod anchor_data_list_holder;
+pub mod paging_predicate_holder;
\ No newline at end of file
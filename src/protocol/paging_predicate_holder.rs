@@ -0,0 +1,35 @@
+use crate::protocol::anchor_data_list_holder::AnchorDataListHolder;
+use crate::serialization::heap_data::HeapData;
+
+#[derive(Default, Clone)]
+pub struct PagingPredicateHolder {
+  pub anchor_data_list_holder: AnchorDataListHolder,
+  pub predicate_data: Option<HeapData>,
+  pub comparator_data: Option<HeapData>,
+  pub page_size: i32,
+  pub page: i32,
+  pub iteration_type_id: u8,
+  pub partition_key_data: Option<HeapData>,
+}
+
+impl PagingPredicateHolder {
+  pub fn new(
+    anchor_data_list_holder: AnchorDataListHolder,
+    predicate_data: Option<HeapData>,
+    comparator_data: Option<HeapData>,
+    page_size: i32,
+    page: i32,
+    iteration_type_id: u8,
+    partition_key_data: Option<HeapData>,
+  ) -> Self {
+    Self {
+      anchor_data_list_holder,
+      predicate_data,
+      comparator_data,
+      page_size,
+      page,
+      iteration_type_id,
+      partition_key_data,
+    }
+  }
+}
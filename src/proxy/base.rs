@@ -4,13 +4,15 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use crate::cluster::service::ClusterService;
+use crate::config::ClientConfig;
 use crate::connection::registry::ConnectionRegistry;
 use crate::core::distributed_object::DistributedObject;
-use crate::invocation::InvocationReturnValue;
+use crate::invocation::{Invocation, InvocationError, InvocationReturnValue};
 use crate::invocation::service::InvocationService;
 use crate::listener::service::ListenerService;
 use crate::partition_service::PartitionService;
 use crate::protocol::client_message::ClientMessage;
+use crate::proxy::lock_reference_id_generator::LockReferenceIdGenerator;
 use crate::proxy::Proxy;
 use crate::serialization::heap_data::HeapData;
 use crate::serialization::schema::Schema;
@@ -29,6 +31,8 @@ pub struct ProxyBase {
   pub connection_registry: Arc<ConnectionRegistry>,
   pub listener_service: Arc<ListenerService>,
   pub cluster_service: Arc<ClusterService>,
+  pub lock_reference_id_generator: Arc<LockReferenceIdGenerator>,
+  pub config: Arc<ClientConfig>,
 }
 
 impl ProxyBase {
@@ -41,6 +45,8 @@ impl ProxyBase {
     serialization_service: Arc<SerializationServiceV1>,
     listener_service: Arc<ListenerService>,
     cluster_service: Arc<ClusterService>,
+    lock_reference_id_generator: Arc<LockReferenceIdGenerator>,
+    config: Arc<ClientConfig>,
   ) -> Self {
     ProxyBase {
       name,
@@ -51,21 +57,61 @@ impl ProxyBase {
       serialization_service,
       listener_service,
       cluster_service,
+      lock_reference_id_generator,
+      config,
     }
   }
   pub fn to_data<T: Serializable + 'static>(&self, object: Box<T>) -> HeapData {
     self.serialization_service.to_data(object)
   }
 
+  /// Partition of data structures that live on a single partition, like
+  /// queues. A name of the form `name@key` is routed by `key`.
+  pub async fn get_partition_id_for_name(&self) -> i32 {
+    self.get_partition_id_for_object(&self.name).await
+  }
+
+  /// Like `get_partition_id_for_name`, for objects the proxy uses internally.
+  pub async fn get_partition_id_for_object(&self, name: &str) -> i32 {
+    let partition_key = match name.find('@') {
+      Some(index) => name[index + 1..].to_string(),
+      None => name.to_string(),
+    };
+    self.partition_service.get_partition_id(self.to_data(Box::new(partition_key))).await
+  }
+
   pub async fn encode_invoke_on_key<R: InvocationReturnValue + Send + Sync + Clone>(&self, key_data: HeapData, encoder: Pin<Box<dyn Send + Sync + Fn(String) -> Pin<Box<dyn Send + Sync + Future<Output=ClientMessage>>>>>, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> R {
     let partition_id = self.partition_service.get_partition_id(key_data).await;
     self.encode_invoke_on_partition(partition_id, encoder, decoder).await
   }
 
-  async fn encode_invoke_on_partition<R: InvocationReturnValue + Send + Sync + Clone>(&self, partition_id: i32, encoder: Pin<Box<dyn Send + Sync + Fn(String) -> Pin<Box<dyn Send + Sync + Future<Output=ClientMessage>>>>>, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> R {
+  pub async fn encode_invoke_on_partition<R: InvocationReturnValue + Send + Sync + Clone>(&self, partition_id: i32, encoder: Pin<Box<dyn Send + Sync + Fn(String) -> Pin<Box<dyn Send + Sync + Future<Output=ClientMessage>>>>>, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> R {
     let client_message = encoder.call((self.name.clone(), )).await;
     self.invocation_service.invoke_on_partition(&self.connection_registry, client_message, partition_id, decoder).await
   }
+
+  /// Like `encode_invoke_on_partition`, for callers that react to the errors
+  /// the members answer with.
+  pub async fn try_encode_invoke_on_partition<R: InvocationReturnValue + Send + Sync + Clone>(&self, partition_id: i32, encoder: Pin<Box<dyn Send + Sync + Fn(String) -> Pin<Box<dyn Send + Sync + Future<Output=ClientMessage>>>>>, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> Result<R, InvocationError> {
+    let client_message = encoder.call((self.name.clone(), )).await;
+    self.invocation_service.try_invoke_on_partition(&self.connection_registry, client_message, partition_id, decoder).await
+  }
+
+  pub async fn encode_invoke<R: InvocationReturnValue + Send + Sync + Clone>(&self, encoder: Pin<Box<dyn Send + Sync + Fn(String) -> Pin<Box<dyn Send + Sync + Future<Output=ClientMessage>>>>>, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> R {
+    let client_message = encoder.call((self.name.clone(), )).await;
+    let mut invocation = Invocation::<Box<Box<R>>>::new(self.invocation_service.clone(), client_message);
+    invocation.handler = Some(decoder);
+    self.invocation_service.invoke(&self.connection_registry, invocation).await
+  }
+
+  /// Like `encode_invoke`, for callers that react to the errors the members
+  /// answer with.
+  pub async fn try_encode_invoke<R: InvocationReturnValue + Send + Sync + Clone>(&self, encoder: Pin<Box<dyn Send + Sync + Fn(String) -> Pin<Box<dyn Send + Sync + Future<Output=ClientMessage>>>>>, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> Result<R, InvocationError> {
+    let client_message = encoder.call((self.name.clone(), )).await;
+    let mut invocation = Invocation::<Box<Box<R>>>::new(self.invocation_service.clone(), client_message);
+    invocation.handler = Some(decoder);
+    self.invocation_service.try_invoke(&self.connection_registry, invocation).await
+  }
 }
 
 pub trait ProxyBaseLogic: Sized + DistributedObject + HasProxyBase + Clone {
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 use crate::core::member::Member;
+use crate::proxy::event_type::EntryEventType;
 
 pub struct EntryEvent<K, V> {
   pub name: String,
@@ -7,18 +8,39 @@ pub struct EntryEvent<K, V> {
   pub value: Option<V>,
   pub old_value: Option<V>,
   pub merging_value: Option<V>,
+  pub event_type: EntryEventType,
   pub member: Option<Arc<Member>>,
 }
 
 impl<K, V> EntryEvent<K, V> {
-  pub fn new(name: String, key: Option<K>, value: Option<V>, old_value: Option<V>, merging_value: Option<V>, member: Option<Arc<Member>>) -> Self {
+  pub fn new(name: String, key: Option<K>, value: Option<V>, old_value: Option<V>, merging_value: Option<V>, event_type: EntryEventType, member: Option<Arc<Member>>) -> Self {
     Self {
       name,
       key,
       value,
       old_value,
       merging_value,
+      event_type,
       member,
     }
   }
-}
\ No newline at end of file
+}
+
+/// Event that affects the whole map, i.e. `EvictAll` or `ClearAll`.
+pub struct MapEvent {
+  pub name: String,
+  pub event_type: EntryEventType,
+  pub number_of_affected_entries: i32,
+  pub member: Option<Arc<Member>>,
+}
+
+impl MapEvent {
+  pub fn new(name: String, event_type: EntryEventType, number_of_affected_entries: i32, member: Option<Arc<Member>>) -> Self {
+    Self {
+      name,
+      event_type,
+      number_of_affected_entries,
+      member,
+    }
+  }
+}
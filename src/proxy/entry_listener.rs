@@ -0,0 +1,96 @@
+use std::future::Future;
+use std::pin::Pin;
+use async_trait_with_sync::async_trait;
+use uuid::Uuid;
+use crate::proxy::base::ProxyBase;
+use crate::proxy::entry_event::{EntryEvent, MapEvent};
+use crate::proxy::event_type::EntryEventType;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+
+/// Receives the raw fields of an entry event from the listener codecs.
+pub(crate) type EntryEventHandler = Pin<Box<dyn Fn(Option<HeapData>, Option<HeapData>, Option<HeapData>, Option<HeapData>, i32, Uuid, i32) -> Pin<Box<dyn Future<Output=()> + Send + Sync>> + Send + Sync>>;
+
+/// Receives the events of a map. Every callback defaults to doing nothing,
+/// so implementations only override the kinds they care about and narrow
+/// `event_types` to keep the members from sending the rest.
+#[async_trait]
+pub trait EntryListener<K: Send + Sync + 'static, V: Send + Sync + 'static>: Send + Sync + 'static {
+  fn event_types(&self) -> Vec<EntryEventType> {
+    EntryEventType::ALL.to_vec()
+  }
+
+  async fn entry_added(&self, _event: EntryEvent<K, V>) {}
+
+  async fn entry_removed(&self, _event: EntryEvent<K, V>) {}
+
+  async fn entry_updated(&self, _event: EntryEvent<K, V>) {}
+
+  async fn entry_evicted(&self, _event: EntryEvent<K, V>) {}
+
+  async fn entry_expired(&self, _event: EntryEvent<K, V>) {}
+
+  async fn entry_merged(&self, _event: EntryEvent<K, V>) {}
+
+  async fn entry_loaded(&self, _event: EntryEvent<K, V>) {}
+
+  async fn map_evicted(&self, _event: MapEvent) {}
+
+  async fn map_cleared(&self, _event: MapEvent) {}
+}
+
+pub(crate) async fn notify_entry_event<K: Send + Sync + 'static, V: Send + Sync + 'static>(listener: &dyn EntryListener<K, V>, event: EntryEvent<K, V>) {
+  match event.event_type {
+    EntryEventType::Added => listener.entry_added(event).await,
+    EntryEventType::Removed => listener.entry_removed(event).await,
+    EntryEventType::Updated => listener.entry_updated(event).await,
+    EntryEventType::Evicted => listener.entry_evicted(event).await,
+    EntryEventType::Expired => listener.entry_expired(event).await,
+    EntryEventType::Merged => listener.entry_merged(event).await,
+    EntryEventType::Loaded => listener.entry_loaded(event).await,
+    _ => {}
+  }
+}
+
+pub(crate) async fn notify_map_event<K: Send + Sync + 'static, V: Send + Sync + 'static>(listener: &dyn EntryListener<K, V>, event: MapEvent) {
+  match event.event_type {
+    EntryEventType::EvictAll => listener.map_evicted(event).await,
+    EntryEventType::ClearAll => listener.map_cleared(event).await,
+    _ => {}
+  }
+}
+
+/// Deserializes the raw fields of an entry event and passes it to `listener`
+/// as an `EntryEvent` or, for map wide events, a `MapEvent`. Events of a type
+/// the client does not know are dropped.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn dispatch_entry_event<K: Serializable + Send + Sync + 'static, V: Serializable + Send + Sync + 'static>(
+  base: &ProxyBase,
+  listener: &dyn EntryListener<K, V>,
+  key: Option<HeapData>,
+  value: Option<HeapData>,
+  old_value: Option<HeapData>,
+  merging_value: Option<HeapData>,
+  event_type: i32,
+  uuid: Uuid,
+  number_of_affected_entries: i32,
+) {
+  let event_type = if let Some(event_type) = EntryEventType::from_id(event_type) { event_type } else { return; };
+  let member = base.cluster_service.get_member(uuid).await;
+  match event_type {
+    EntryEventType::EvictAll | EntryEventType::ClearAll => {
+      notify_map_event(listener, MapEvent::new(base.name.clone(), event_type, number_of_affected_entries, member)).await;
+    }
+    _ => {
+      notify_entry_event(listener, EntryEvent::new(
+        base.name.clone(),
+        if let Some(key) = key { Some(*base.serialization_service.to_object(key).await) } else { None },
+        if let Some(value) = value { Some(*base.serialization_service.to_object(value).await) } else { None },
+        if let Some(old_value) = old_value { Some(*base.serialization_service.to_object(old_value).await) } else { None },
+        if let Some(merging_value) = merging_value { Some(*base.serialization_service.to_object(merging_value).await) } else { None },
+        event_type,
+        member,
+      )).await;
+    }
+  }
+}
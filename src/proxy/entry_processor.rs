@@ -0,0 +1,8 @@
+use crate::serialization::serializable::Serializable;
+
+/// Logic that runs on the member owning an entry, serialized like a
+/// [`ServerSideFunction`](crate::proxy::server_side_function::ServerSideFunction).
+/// `Output` is the type the member answers with for each entry.
+pub trait EntryProcessor: Serializable + Send + Sync + 'static {
+  type Output: Send + Sync + Clone + 'static;
+}
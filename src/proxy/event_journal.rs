@@ -0,0 +1,130 @@
+use crate::proxy::event_type::EntryEventType;
+use crate::serialization::data::{DataInput, DataOutput};
+use crate::serialization::data::object_data_input::ObjectDataInput;
+use crate::serialization::data::object_data_output::ObjectDataOutput;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableInfo, IdentifiedDataSerializableSerialization};
+
+pub const MAP_DATA_FACTORY_ID: i32 = -4;
+pub const EVENT_JOURNAL_INTERNAL_MAP_EVENT_CLASS_ID: i32 = 105;
+
+/// Position of a reader in the journal of a single partition. Passing it back
+/// to `MapProxy::read_from_event_journal` continues right after the last
+/// event that was handed out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventJournalResumeToken {
+  pub partition_id: i32,
+  pub sequence: i64,
+}
+
+/// Where `MapProxy::read_from_event_journal` starts reading. A sequence
+/// applies to every partition, resume tokens continue each partition where
+/// it was left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventJournalStart {
+  Sequence(i64),
+  Resume(Vec<EventJournalResumeToken>),
+}
+
+impl From<i64> for EventJournalStart {
+  fn from(sequence: i64) -> Self {
+    EventJournalStart::Sequence(sequence)
+  }
+}
+
+impl From<Vec<EventJournalResumeToken>> for EventJournalStart {
+  fn from(resume_tokens: Vec<EventJournalResumeToken>) -> Self {
+    EventJournalStart::Resume(resume_tokens)
+  }
+}
+
+/// A journal event. If the journal is read with a projection, the members
+/// send its result instead, which is handed out as `new_value` while `key`,
+/// `old_value` and `event_type` stay empty.
+#[derive(Debug, Clone)]
+pub struct EventJournalMapEvent<K, V> {
+  pub key: Option<K>,
+  pub new_value: Option<V>,
+  pub old_value: Option<V>,
+  pub event_type: Option<EntryEventType>,
+  pub partition_id: i32,
+  pub sequence: i64,
+  /// Number of events that were overwritten in the journal before they
+  /// could be read, directly preceding this one.
+  pub lost_events: i64,
+}
+
+impl<K, V> EventJournalMapEvent<K, V> {
+  pub fn resume_token(&self) -> EventJournalResumeToken {
+    EventJournalResumeToken {
+      partition_id: self.partition_id,
+      sequence: self.sequence + 1,
+    }
+  }
+}
+
+/// Journal event as the member sends it, before key and values are
+/// deserialized.
+#[derive(Debug, Clone, Default)]
+pub struct InternalEventJournalMapEvent {
+  pub key: Option<HeapData>,
+  pub new_value: Option<HeapData>,
+  pub old_value: Option<HeapData>,
+  pub event_type: i32,
+}
+
+impl IdentifiedDataSerializableInfo for InternalEventJournalMapEvent {
+  fn factory_id(&self) -> i32 {
+    MAP_DATA_FACTORY_ID
+  }
+
+  fn class_id(&self) -> i32 {
+    EVENT_JOURNAL_INTERNAL_MAP_EVENT_CLASS_ID
+  }
+}
+
+impl IdentifiedDataSerializableSerialization for InternalEventJournalMapEvent {
+  fn read_data(&mut self, input: &mut ObjectDataInput) {
+    self.key = input.read_data();
+    self.new_value = input.read_data();
+    self.old_value = input.read_data();
+    self.event_type = input.read_int();
+  }
+
+  fn write_data(&mut self, output: &mut ObjectDataOutput) {
+    output.write_data(self.key.as_ref());
+    output.write_data(self.new_value.as_ref());
+    output.write_data(self.old_value.as_ref());
+    output.write_int(self.event_type);
+  }
+}
+
+/// A map data object the client has no counterpart for. Its fields are
+/// skipped.
+#[derive(Debug, Clone)]
+pub struct UnknownMapData {
+  pub class_id: i32,
+}
+
+impl IdentifiedDataSerializableInfo for UnknownMapData {
+  fn factory_id(&self) -> i32 {
+    MAP_DATA_FACTORY_ID
+  }
+
+  fn class_id(&self) -> i32 {
+    self.class_id
+  }
+}
+
+impl IdentifiedDataSerializableSerialization for UnknownMapData {
+  fn read_data(&mut self, _input: &mut ObjectDataInput) {}
+
+  fn write_data(&mut self, _output: &mut ObjectDataOutput) {}
+}
+
+pub fn map_data_factory(class_id: i32) -> Box<dyn IdentifiedDataSerializable> {
+  match class_id {
+    EVENT_JOURNAL_INTERNAL_MAP_EVENT_CLASS_ID => Box::new(InternalEventJournalMapEvent::default()),
+    class_id => Box::new(UnknownMapData { class_id }),
+  }
+}
@@ -1,15 +1,75 @@
-pub struct EventType;
-
-impl EventType {
-  pub const ADDED: i32 = 1 << 0;
-  pub const REMOVED: i32 = 1 << 1;
-  pub const UPDATED: i32 = 1 << 2;
-  pub const EVICTED: i32 = 1 << 3;
-  pub const EXPIRED: i32 = 1 << 4;
-  pub const EVICT_ALL: i32 = 1 << 5;
-  pub const CLEAR_ALL: i32 = 1 << 6;
-  pub const MERGED: i32 = 1 << 7;
-  pub const INVALIDATED: i32 = 1 << 8;
-  pub const LOADED: i32 = 1 << 9;
-  pub const ALL: i32 = Self::ADDED | Self::REMOVED | Self::UPDATED | Self::EVICTED | Self::EXPIRED | Self::EVICT_ALL | Self::CLEAR_ALL | Self::MERGED | Self::INVALIDATED | Self::LOADED;
-}
\ No newline at end of file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntryEventType {
+  Added = 1 << 0,
+  Removed = 1 << 1,
+  Updated = 1 << 2,
+  Evicted = 1 << 3,
+  Expired = 1 << 4,
+  EvictAll = 1 << 5,
+  ClearAll = 1 << 6,
+  Merged = 1 << 7,
+  Invalidated = 1 << 8,
+  Loaded = 1 << 9,
+}
+
+impl EntryEventType {
+  pub const ALL: [EntryEventType; 10] = [
+    EntryEventType::Added,
+    EntryEventType::Removed,
+    EntryEventType::Updated,
+    EntryEventType::Evicted,
+    EntryEventType::Expired,
+    EntryEventType::EvictAll,
+    EntryEventType::ClearAll,
+    EntryEventType::Merged,
+    EntryEventType::Invalidated,
+    EntryEventType::Loaded,
+  ];
+
+  pub fn id(&self) -> i32 {
+    *self as i32
+  }
+
+  pub fn from_id(id: i32) -> Option<Self> {
+    Self::ALL.into_iter().find(|event_type| event_type.id() == id)
+  }
+
+  /// Combines the event types into the listener flags sent to the members.
+  pub fn flags(event_types: &[EntryEventType]) -> i32 {
+    event_types.iter().fold(0, |flags, event_type| flags | event_type.id())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ids_are_distinct_bits() {
+    for event_type in EntryEventType::ALL {
+      assert_eq!(event_type.id().count_ones(), 1);
+    }
+    assert_eq!(EntryEventType::flags(&EntryEventType::ALL), (1 << 10) - 1);
+  }
+
+  #[test]
+  fn from_id_round_trips() {
+    for event_type in EntryEventType::ALL {
+      assert_eq!(EntryEventType::from_id(event_type.id()), Some(event_type));
+    }
+  }
+
+  #[test]
+  fn from_id_rejects_unknown_and_combined_ids() {
+    assert_eq!(EntryEventType::from_id(0), None);
+    assert_eq!(EntryEventType::from_id(1 << 10), None);
+    assert_eq!(EntryEventType::from_id(EntryEventType::flags(&[EntryEventType::Added, EntryEventType::Removed])), None);
+  }
+
+  #[test]
+  fn flags_combine_event_types() {
+    assert_eq!(EntryEventType::flags(&[]), 0);
+    assert_eq!(EntryEventType::flags(&[EntryEventType::Added, EntryEventType::Loaded]), 1 | 1 << 9);
+    assert_eq!(EntryEventType::flags(&[EntryEventType::Updated, EntryEventType::Updated]), 1 << 2);
+  }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use crate::core::member::Member;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemEventType {
+  Added = 1,
+  Removed = 2,
+}
+
+impl ItemEventType {
+  pub fn id(&self) -> i32 {
+    *self as i32
+  }
+
+  pub fn from_id(id: i32) -> Option<Self> {
+    match id {
+      1 => Some(ItemEventType::Added),
+      2 => Some(ItemEventType::Removed),
+      _ => None,
+    }
+  }
+}
+
+/// Event of a queue, list or set. `item` is only set when the listener was
+/// registered with `include_value`.
+pub struct ItemEvent<T> {
+  pub name: String,
+  pub item: Option<T>,
+  pub event_type: ItemEventType,
+  pub member: Option<Arc<Member>>,
+}
+
+impl<T> ItemEvent<T> {
+  pub fn new(name: String, item: Option<T>, event_type: ItemEventType, member: Option<Arc<Member>>) -> Self {
+    Self {
+      name,
+      item,
+      event_type,
+      member,
+    }
+  }
+}
@@ -0,0 +1,38 @@
+use async_trait_with_sync::async_trait;
+use uuid::Uuid;
+use crate::proxy::base::ProxyBase;
+use crate::proxy::item_event::{ItemEvent, ItemEventType};
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+
+/// Receives the events of a queue, list or set. Both callbacks default to
+/// doing nothing.
+#[async_trait]
+pub trait ItemListener<T: Send + Sync + 'static>: Send + Sync + 'static {
+  async fn item_added(&self, _event: ItemEvent<T>) {}
+
+  async fn item_removed(&self, _event: ItemEvent<T>) {}
+}
+
+pub(crate) async fn notify_item_event<T: Send + Sync + 'static>(listener: &dyn ItemListener<T>, event: ItemEvent<T>) {
+  match event.event_type {
+    ItemEventType::Added => listener.item_added(event).await,
+    ItemEventType::Removed => listener.item_removed(event).await,
+  }
+}
+
+/// Turns an item event as decoded by the add listener codecs of queues, lists
+/// and sets into an `ItemEvent`. Events of a type the client does not know
+/// are dropped.
+pub(crate) async fn dispatch_item_event<T: Serializable + Send + Sync + 'static>(
+  base: &ProxyBase,
+  listener: &dyn ItemListener<T>,
+  item: Option<HeapData>,
+  uuid: Uuid,
+  event_type: i32,
+) {
+  let event_type = if let Some(event_type) = ItemEventType::from_id(event_type) { event_type } else { return; };
+  let member = base.cluster_service.get_member(uuid).await;
+  let item = if let Some(item) = item { Some(*base.serialization_service.to_object(item).await) } else { None };
+  notify_item_event(listener, ItemEvent::new(base.name.clone(), item, event_type, member)).await;
+}
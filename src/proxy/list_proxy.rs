@@ -0,0 +1,420 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::transmute;
+use std::pin::Pin;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use crate::codec::list_add_all_codec::ListAddAllCodec;
+use crate::codec::list_add_all_with_index_codec::ListAddAllWithIndexCodec;
+use crate::codec::list_add_codec::ListAddCodec;
+use crate::codec::list_add_listener_codec::ListAddListenerCodec;
+use crate::codec::list_add_with_index_codec::ListAddWithIndexCodec;
+use crate::codec::list_clear_codec::ListClearCodec;
+use crate::codec::list_compare_and_remove_all_codec::ListCompareAndRemoveAllCodec;
+use crate::codec::list_compare_and_retain_all_codec::ListCompareAndRetainAllCodec;
+use crate::codec::list_contains_all_codec::ListContainsAllCodec;
+use crate::codec::list_contains_codec::ListContainsCodec;
+use crate::codec::list_get_all_codec::ListGetAllCodec;
+use crate::codec::list_get_codec::ListGetCodec;
+use crate::codec::list_index_of_codec::ListIndexOfCodec;
+use crate::codec::list_is_empty_codec::ListIsEmptyCodec;
+use crate::codec::list_last_index_of_codec::ListLastIndexOfCodec;
+use crate::codec::list_remove_codec::ListRemoveCodec;
+use crate::codec::list_remove_listener_codec::ListRemoveListenerCodec;
+use crate::codec::list_remove_with_index_codec::ListRemoveWithIndexCodec;
+use crate::codec::list_set_codec::ListSetCodec;
+use crate::codec::list_size_codec::ListSizeCodec;
+use crate::codec::list_sub_codec::ListSubCodec;
+use crate::core::readonly_lazy_list::ReadOnlyLazyList;
+use crate::listener::message_codec::ListenerMessageCodec;
+use crate::protocol::client_message::ClientMessage;
+use crate::proxy::base::{HasProxyBase, ProxyBase};
+use crate::proxy::item_listener::{dispatch_item_event, ItemListener};
+use crate::proxy::map_proxy::AnySend;
+use crate::proxy::Proxy;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+use crate::util::maybe_future::MaybeFuture;
+
+/// Like queues, a list lives on the single partition resolved from its name.
+#[derive(Clone)]
+pub struct ListProxy<T: Serializable> {
+  base: ProxyBase,
+  partition_id: i32,
+  phantom: PhantomData<T>,
+}
+
+#[derive(Clone)]
+struct ListItemListenerCodec {
+  name: String,
+  include_value: bool,
+}
+
+impl ListItemListenerCodec {
+  pub fn new(name: String, include_value: bool) -> Self {
+    Self {
+      name,
+      include_value,
+    }
+  }
+}
+
+impl ListenerMessageCodec for ListItemListenerCodec {
+  fn encode_add_request<'a>(&'a self, local_only: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    ListAddListenerCodec::encode_request(&self.name, &self.include_value, local_only)
+  }
+
+  fn decode_add_response<'a>(&'a self, client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Uuid> + Send + Sync + 'a>> {
+    ListAddListenerCodec::decode_response(client_message)
+  }
+
+  fn encode_remove_request<'a>(&'a self, registration_id: &'a Uuid) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    ListRemoveListenerCodec::encode_request(&self.name, registration_id)
+  }
+}
+
+impl<T: Serializable + Clone + Send + Sync + 'static> ListProxy<T> {
+  pub fn new(
+    base: ProxyBase,
+    partition_id: i32,
+  ) -> Self {
+    ListProxy {
+      base,
+      partition_id,
+      phantom: PhantomData::default(),
+    }
+  }
+
+  pub async fn get(&self, index: i32) -> Option<T> {
+    let item_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(move |name| Box::pin(async move { ListGetCodec::encode_request(&name, &index).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListGetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_item(item_data).await
+  }
+
+  /// Replaces the item at `index` and returns the previous one.
+  pub async fn set(&self, index: i32, item: impl Into<T>) -> Option<T> {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    let old_item_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            ListSetCodec::encode_request(&name, &index, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListSetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_item(old_item_data).await
+  }
+
+  /// Appends the item to the end of the list.
+  pub async fn add(&self, item: impl Into<T>) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            ListAddCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListAddCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Inserts the item at `index`, shifting the following items.
+  pub async fn add_at(&self, index: i32, item: impl Into<T>) {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            ListAddWithIndexCodec::encode_request(&name, &index, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Removes the first occurrence of the item.
+  pub async fn remove(&self, item: impl Into<T>) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            ListRemoveCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListRemoveCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn remove_at(&self, index: i32) -> Option<T> {
+    let item_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(move |name| Box::pin(async move { ListRemoveWithIndexCodec::encode_request(&name, &index).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListRemoveWithIndexCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_item(item_data).await
+  }
+
+  /// `-1` if the list does not contain the item.
+  pub async fn index_of(&self, item: impl Into<T>) -> i32 {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            ListIndexOfCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListIndexOfCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn last_index_of(&self, item: impl Into<T>) -> i32 {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            ListLastIndexOfCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListLastIndexOfCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn size(&self) -> i32 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { ListSizeCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListSizeCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn is_empty(&self) -> bool {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { ListIsEmptyCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListIsEmptyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains(&self, item: impl Into<T>) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            ListContainsCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListContainsCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            ListContainsAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListContainsAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Appends the items in iteration order. Returns `true` if the list changed.
+  pub async fn add_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            ListAddAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListAddAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn add_all_at(&self, index: i32, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            ListAddAllWithIndexCodec::encode_request(&name, &index, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListAddAllWithIndexCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Removes every item that is contained in `items`.
+  pub async fn remove_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            ListCompareAndRemoveAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListCompareAndRemoveAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Removes every item that is not contained in `items`.
+  pub async fn retain_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            ListCompareAndRetainAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListCompareAndRetainAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn clear(&self) {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { ListClearCodec::encode_request(&name).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn get_all(&self) -> ReadOnlyLazyList<T> {
+    let items_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { ListGetAllCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListGetAllCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_lazy_list(items_data)
+  }
+
+  /// Items from `from` (inclusive) to `to` (exclusive). Unlike its Java
+  /// counterpart this is a copy, not a view backed by the list.
+  pub async fn sub_list(&self, from: i32, to: i32) -> ReadOnlyLazyList<T> {
+    let items_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(move |name| Box::pin(async move { ListSubCodec::encode_request(&name, &from, &to).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ListSubCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_lazy_list(items_data)
+  }
+
+  /// Returns the registration id to pass to `remove_item_listener`.
+  pub async fn add_item_listener(&self, listener: impl ItemListener<T>, include_value: bool) -> Uuid {
+    let codec = ListItemListenerCodec::new(self.base.name.clone(), include_value);
+    let listener = Arc::new(listener);
+    let base = self.base.clone();
+    self.base.listener_service.register_listener(codec.clone(), {
+      move |mut client_message| {
+        let listener = listener.clone();
+        let base = base.clone();
+        Box::pin(async move {
+          ListAddListenerCodec::handle(&mut client_message, Some(Box::pin({
+            move |item, uuid, event_type| {
+              let listener = listener.clone();
+              let base = base.clone();
+              Box::pin(async move {
+                dispatch_item_event(&base, &*listener, item, uuid, event_type).await;
+              })
+            }
+          }))).await;
+        })
+      }
+    }).await
+  }
+
+  pub async fn remove_item_listener(&self, registration_id: Uuid) -> bool {
+    self.base.listener_service.deregister_listener(registration_id).await
+  }
+
+  fn to_items_data(&self, items: impl IntoIterator<Item=T>) -> Vec<HeapData> {
+    items.into_iter().map(|item| self.base.to_data(Box::new(item))).collect()
+  }
+
+  async fn to_item(&self, item_data: Option<HeapData>) -> Option<T> {
+    match item_data {
+      Some(item_data) => Some(*self.base.serialization_service.to_object(item_data).await),
+      None => None,
+    }
+  }
+
+  fn to_lazy_list(&self, items_data: Vec<HeapData>) -> ReadOnlyLazyList<T> {
+    ReadOnlyLazyList::new(items_data, self.base.serialization_service.clone(), |serialization_service, item_data| {
+      Box::pin(async move { *serialization_service.to_object::<T>(item_data).await })
+    })
+  }
+}
+
+impl<T: Clone + Send + Sync + Serializable + 'static> Proxy for ListProxy<T> {
+  const SERVICE_NAME: &'static str = "hz:impl:listService";
+  fn get_proxies() -> Arc<RwLock<HashMap<String, Box<MaybeFuture<Self>>>>> {
+    lazy_static! {
+      static ref PROXIES: Arc<RwLock<HashMap<String, Box<dyn AnySend>>>> = Arc::new(RwLock::new(HashMap::new()));
+    }
+    unsafe { transmute(PROXIES.clone()) }
+  }
+  fn create_proxy(base: ProxyBase) -> Pin<Box<dyn Future<Output=Self> + Send + Sync>> {
+    Box::pin(async move {
+      let partition_id = base.get_partition_id_for_name().await;
+      Self::new(base, partition_id)
+    })
+  }
+}
+
+impl<T: Send + Sync + Serializable + 'static> HasProxyBase for ListProxy<T> {
+  fn get_proxy_base(&self) -> &ProxyBase {
+    &self.base
+  }
+}
@@ -0,0 +1,34 @@
+use std::future::Future;
+use std::pin::Pin;
+use tokio::runtime::Handle;
+
+/// Holds an acquired lock and releases it when dropped. Prefer [`LockGuard::unlock`]
+/// where possible, dropping only schedules the release on the runtime and leaves
+/// the lock to its lease if there is no runtime anymore.
+pub struct LockGuard {
+  unlock: Option<Pin<Box<dyn Future<Output=()> + Send + Sync>>>,
+}
+
+impl LockGuard {
+  pub fn new(unlock: impl Future<Output=()> + Send + Sync + 'static) -> Self {
+    Self {
+      unlock: Some(Box::pin(unlock)),
+    }
+  }
+
+  pub async fn unlock(mut self) {
+    if let Some(unlock) = self.unlock.take() {
+      unlock.await;
+    }
+  }
+}
+
+impl Drop for LockGuard {
+  fn drop(&mut self) {
+    if let Some(unlock) = self.unlock.take() {
+      if let Ok(handle) = Handle::try_current() {
+        handle.spawn(unlock);
+      }
+    }
+  }
+}
@@ -0,0 +1,17 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+
+pub struct LockReferenceIdGenerator {
+  reference_id_counter: AtomicI64,
+}
+
+impl LockReferenceIdGenerator {
+  pub fn new() -> Self {
+    Self {
+      reference_id_counter: AtomicI64::new(0),
+    }
+  }
+
+  pub fn get_next_reference_id(&self) -> i64 {
+    self.reference_id_counter.fetch_add(1, Ordering::SeqCst) + 1
+  }
+}
@@ -6,6 +6,7 @@ use crate::invocation::service::InvocationService;
 use crate::invocation::InvocationReturnValue;
 use crate::protocol::client_message::ClientMessage;
 use crate::proxy::base::ProxyBase;
+use crate::proxy::lock_reference_id_generator::LockReferenceIdGenerator;
 use crate::proxy::map_proxy::MapProxy;
 use crate::proxy::Proxy;
 use crate::util::maybe_future::MaybeFuture;
@@ -18,6 +19,7 @@ use std::string::ToString;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use crate::cluster::service::ClusterService;
+use crate::config::ClientConfig;
 use crate::listener::service::ListenerService;
 use crate::partition_service::PartitionService;
 use crate::serialization::service::SerializationServiceV1;
@@ -30,6 +32,8 @@ pub struct ProxyManager {
   pub serialization_service: Arc<SerializationServiceV1>,
   pub listener_service: Arc<ListenerService>,
   pub cluster_service: Arc<ClusterService>,
+  pub lock_reference_id_generator: Arc<LockReferenceIdGenerator>,
+  pub config: Arc<ClientConfig>,
 }
 
 impl ProxyManager {
@@ -42,6 +46,8 @@ impl ProxyManager {
     serialization_service: Arc<SerializationServiceV1>,
     listener_service: Arc<ListenerService>,
     cluster_service: Arc<ClusterService>,
+    lock_reference_id_generator: Arc<LockReferenceIdGenerator>,
+    config: Arc<ClientConfig>,
   ) -> Self {
     ProxyManager {
       partition_service,
@@ -50,6 +56,8 @@ impl ProxyManager {
       serialization_service,
       listener_service,
       cluster_service,
+      lock_reference_id_generator,
+      config,
     }
   }
 
@@ -115,7 +123,13 @@ impl ProxyManager {
     //todo: add reliabletopic proxy
     //todo: add flake id generator proxy
 
-    T::create_proxy(ProxyBase::new(
+    T::create_proxy(self.new_proxy_base(name, service_name)).await
+  }
+
+  /// Also used for proxies that are not created through `get_or_create_proxy`,
+  /// like the ones of the CP subsystem.
+  pub(crate) fn new_proxy_base(&self, name: String, service_name: String) -> ProxyBase {
+    ProxyBase::new(
       name,
       service_name,
       self.connection_registry.clone(),
@@ -124,7 +138,9 @@ impl ProxyManager {
       self.serialization_service.clone(),
       self.listener_service.clone(),
       self.cluster_service.clone(),
-    )).await
+      self.lock_reference_id_generator.clone(),
+      self.config.clone(),
+    )
   }
 
   async fn create_proxy<T: Proxy + Sized>(
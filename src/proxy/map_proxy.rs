@@ -4,30 +4,111 @@ use std::future::Future;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::mem::transmute;
+use std::num::NonZeroU32;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future::join_all;
+use futures::stream::{self, Stream, StreamExt};
 use lazy_static::lazy_static;
 use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
 use uuid::Uuid;
 use crate::codec::map_add_entry_listener_codec::MapAddEntryListenerCodec;
+use crate::codec::map_add_entry_listener_to_key_codec::MapAddEntryListenerToKeyCodec;
+use crate::codec::map_add_entry_listener_to_key_with_predicate_codec::MapAddEntryListenerToKeyWithPredicateCodec;
+use crate::codec::map_add_entry_listener_with_predicate_codec::MapAddEntryListenerWithPredicateCodec;
+use crate::codec::map_add_index_codec::MapAddIndexCodec;
+use crate::codec::map_aggregate_codec::MapAggregateCodec;
+use crate::codec::map_aggregate_with_predicate_codec::MapAggregateWithPredicateCodec;
 
+use crate::codec::map_get_all_codec::MapGetAllCodec;
+use crate::codec::map_clear_codec::MapClearCodec;
+use crate::codec::map_contains_key_codec::MapContainsKeyCodec;
+use crate::codec::map_contains_value_codec::MapContainsValueCodec;
+use crate::codec::map_delete_codec::MapDeleteCodec;
+use crate::codec::map_entries_with_paging_predicate_codec::MapEntriesWithPagingPredicateCodec;
+use crate::codec::map_entries_with_predicate_codec::MapEntriesWithPredicateCodec;
+use crate::codec::map_entry_set_codec::MapEntrySetCodec;
+use crate::codec::map_event_journal_read_codec::MapEventJournalReadCodec;
+use crate::codec::map_event_journal_subscribe_codec::MapEventJournalSubscribeCodec;
+use crate::codec::map_execute_on_all_keys_codec::MapExecuteOnAllKeysCodec;
+use crate::codec::map_execute_on_key_codec::MapExecuteOnKeyCodec;
+use crate::codec::map_execute_on_keys_codec::MapExecuteOnKeysCodec;
+use crate::codec::map_execute_with_predicate_codec::MapExecuteWithPredicateCodec;
+use crate::codec::map_fetch_entries_codec::MapFetchEntriesCodec;
+use crate::codec::map_fetch_keys_codec::MapFetchKeysCodec;
+use crate::codec::map_force_unlock_codec::MapForceUnlockCodec;
 use crate::codec::map_get_codec::MapGetCodec;
+use crate::codec::map_get_entry_view_codec::{MapGetEntryViewCodec, MapGetEntryViewResponseParams};
+use crate::codec::map_is_empty_codec::MapIsEmptyCodec;
+use crate::codec::map_is_locked_codec::MapIsLockedCodec;
+use crate::codec::map_key_set_codec::MapKeySetCodec;
+use crate::codec::map_key_set_with_paging_predicate_codec::MapKeySetWithPagingPredicateCodec;
+use crate::codec::map_key_set_with_predicate_codec::MapKeySetWithPredicateCodec;
+use crate::codec::map_lock_codec::MapLockCodec;
+use crate::codec::map_project_codec::MapProjectCodec;
+use crate::codec::map_project_with_predicate_codec::MapProjectWithPredicateCodec;
+use crate::codec::map_put_all_codec::MapPutAllCodec;
 use crate::codec::map_put_codec::MapPutCodec;
+use crate::codec::map_put_if_absent_codec::MapPutIfAbsentCodec;
+use crate::codec::map_put_if_absent_with_max_idle_codec::MapPutIfAbsentWithMaxIdleCodec;
+use crate::codec::map_put_transient_codec::MapPutTransientCodec;
+use crate::codec::map_put_transient_with_max_idle_codec::MapPutTransientWithMaxIdleCodec;
+use crate::codec::map_put_with_max_idle_codec::MapPutWithMaxIdleCodec;
+use crate::codec::map_remove_all_codec::MapRemoveAllCodec;
 use crate::codec::map_remove_codec::MapRemoveCodec;
+use crate::codec::map_remove_if_same_codec::MapRemoveIfSameCodec;
+use crate::codec::map_replace_codec::MapReplaceCodec;
+use crate::codec::map_replace_if_same_codec::MapReplaceIfSameCodec;
+use crate::codec::map_set_codec::MapSetCodec;
+use crate::codec::map_set_ttl_codec::MapSetTtlCodec;
+use crate::codec::map_set_with_max_idle_codec::MapSetWithMaxIdleCodec;
+use crate::codec::map_size_codec::MapSizeCodec;
+use crate::codec::map_submit_to_key_codec::MapSubmitToKeyCodec;
+use crate::codec::map_try_put_codec::MapTryPutCodec;
+use crate::codec::map_try_lock_codec::MapTryLockCodec;
+use crate::codec::map_try_remove_codec::MapTryRemoveCodec;
+use crate::codec::map_unlock_codec::MapUnlockCodec;
+use crate::codec::map_values_codec::MapValuesCodec;
+use crate::codec::map_values_with_paging_predicate_codec::MapValuesWithPagingPredicateCodec;
+use crate::codec::map_values_with_predicate_codec::MapValuesWithPredicateCodec;
 use crate::codec::map_remove_entry_listener_codec::MapRemoveEntryListenerCodec;
+use crate::codec::map_remove_interceptor_codec::MapRemoveInterceptorCodec;
+use crate::codec::map_add_interceptor_codec::MapAddInterceptorCodec;
+use crate::codec::map_load_given_keys_codec::MapLoadGivenKeysCodec;
+use crate::codec::map_load_all_codec::MapLoadAllCodec;
+use crate::codec::map_flush_codec::MapFlushCodec;
+use crate::codec::map_evict_all_codec::MapEvictAllCodec;
+use crate::codec::map_evict_codec::MapEvictCodec;
+use crate::config::index::{IndexConfig, InvalidIndexConfigError};
+use crate::core::entry_view::EntryView;
+use crate::core::readonly_lazy_list::ReadOnlyLazyList;
 use crate::listener::message_codec::ListenerMessageCodec;
 use crate::nested_state::NestedStateInsert;
+use crate::protocol::anchor_data_list_holder::AnchorDataListHolder;
 use crate::protocol::client_message::ClientMessage;
+use crate::protocol::paging_predicate_holder::PagingPredicateHolder;
 use crate::proxy::base::{HasProxyBase, ProxyBase};
-use crate::proxy::entry_event::EntryEvent;
+use crate::proxy::entry_listener::{dispatch_entry_event, EntryEventHandler, EntryListener};
+use crate::proxy::entry_processor::EntryProcessor;
+use crate::proxy::event_journal::{EventJournalMapEvent, EventJournalResumeToken, EventJournalStart, InternalEventJournalMapEvent};
+use crate::proxy::event_type::EntryEventType;
+use crate::proxy::lock_guard::LockGuard;
 use crate::proxy::Proxy;
+use crate::proxy::server_side_function::ServerSideFunction;
 use crate::proxy::strong_map_proxy::StrongMapProxy;
 use crate::proxy::weak_map_proxy::WeakMapProxy;
+use crate::serialization::aggregation::aggregator::Aggregator;
+use crate::serialization::default_predicates::Predicate;
 use crate::serialization::heap_data::HeapData;
+use crate::serialization::paging_predicate::{IterationType, PagingPredicate};
+use crate::serialization::projection::Projection;
+use crate::serialization::query_result::{QueryResult, QueryResultTypeError};
 use crate::serialization::serializable::Serializable;
 use crate::util::maybe_future::MaybeFuture;
+use crate::util::thread_id::{MissingThreadIdScopeError, ThreadId};
 
 #[derive(Clone)]
 pub struct MapProxy<K: Serializable, V: Serializable> {
@@ -35,31 +116,53 @@ pub struct MapProxy<K: Serializable, V: Serializable> {
   phantom: PhantomData<(K, V)>,
 }
 
-pub trait MapListener<K, V, const FLAGS: i32> = Fn(EntryEvent<K, V>) -> Pin<Box<dyn Send + Sync + Future<Output=()>>> + Send + Sync + 'static;
-
+#[derive(Clone)]
 struct EntryListenerCodec {
   name: String,
   include_value: bool,
   flags: i32,
+  key: Option<HeapData>,
+  predicate: Option<HeapData>,
 }
 
 impl EntryListenerCodec {
-  pub fn new(name: String, include_value: bool, flags: i32) -> Self {
+  pub fn new(name: String, include_value: bool, flags: i32, key: Option<HeapData>, predicate: Option<HeapData>) -> Self {
     Self {
       name,
       include_value,
       flags,
+      key,
+      predicate,
+    }
+  }
+
+  async fn handle(&self, client_message: &mut ClientMessage, handle_entry_event: Option<EntryEventHandler>) {
+    match (&self.key, &self.predicate) {
+      (None, None) => MapAddEntryListenerCodec::handle(client_message, handle_entry_event).await,
+      (Some(_), None) => MapAddEntryListenerToKeyCodec::handle(client_message, handle_entry_event).await,
+      (None, Some(_)) => MapAddEntryListenerWithPredicateCodec::handle(client_message, handle_entry_event).await,
+      (Some(_), Some(_)) => MapAddEntryListenerToKeyWithPredicateCodec::handle(client_message, handle_entry_event).await,
     }
   }
 }
 
 impl ListenerMessageCodec for EntryListenerCodec {
   fn encode_add_request<'a>(&'a self, local_only: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
-    MapAddEntryListenerCodec::encode_request(&self.name, &self.include_value, &self.flags, local_only)
+    match (&self.key, &self.predicate) {
+      (None, None) => MapAddEntryListenerCodec::encode_request(&self.name, &self.include_value, &self.flags, local_only),
+      (Some(key), None) => MapAddEntryListenerToKeyCodec::encode_request(&self.name, key, &self.include_value, &self.flags, local_only),
+      (None, Some(predicate)) => MapAddEntryListenerWithPredicateCodec::encode_request(&self.name, predicate, &self.include_value, &self.flags, local_only),
+      (Some(key), Some(predicate)) => MapAddEntryListenerToKeyWithPredicateCodec::encode_request(&self.name, key, predicate, &self.include_value, &self.flags, local_only),
+    }
   }
 
   fn decode_add_response<'a>(&'a self, client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Uuid> + Send + Sync + 'a>> {
-    MapAddEntryListenerCodec::decode_response(client_message)
+    match (&self.key, &self.predicate) {
+      (None, None) => MapAddEntryListenerCodec::decode_response(client_message),
+      (Some(_), None) => MapAddEntryListenerToKeyCodec::decode_response(client_message),
+      (None, Some(_)) => MapAddEntryListenerWithPredicateCodec::decode_response(client_message),
+      (Some(_), Some(_)) => MapAddEntryListenerToKeyWithPredicateCodec::decode_response(client_message),
+    }
   }
 
   fn encode_remove_request<'a>(&'a self, registration_id: &'a Uuid) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
@@ -77,77 +180,437 @@ impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static
     }
   }
 
-  pub async fn add_entry_listener<const FLAGS: i32>(&self, listener: impl MapListener<K, V, FLAGS>) {
+  /// Returns the registration id to pass to `remove_entry_listener`. With
+  /// `include_value` set to false, events carry no values.
+  pub async fn add_entry_listener(&self, listener: impl EntryListener<K, V>, include_value: bool) -> Uuid {
+    self.add_entry_listener_internal(listener, include_value, None, None).await
+  }
+
+  pub async fn add_entry_listener_to_key(&self, listener: impl EntryListener<K, V>, key: impl Into<K>, include_value: bool) -> Uuid {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.add_entry_listener_internal(listener, include_value, Some(key_data), None).await
+  }
+
+  pub async fn add_entry_listener_with_predicate(&self, listener: impl EntryListener<K, V>, predicate: Predicate, include_value: bool) -> Uuid {
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    self.add_entry_listener_internal(listener, include_value, None, Some(predicate_data)).await
+  }
+
+  pub async fn add_entry_listener_to_key_with_predicate(&self, listener: impl EntryListener<K, V>, key: impl Into<K>, predicate: Predicate, include_value: bool) -> Uuid {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    self.add_entry_listener_internal(listener, include_value, Some(key_data), Some(predicate_data)).await
+  }
+
+  pub async fn remove_entry_listener(&self, registration_id: Uuid) -> bool {
+    self.base.listener_service.deregister_listener(registration_id).await
+  }
+
+  async fn add_entry_listener_internal(&self, listener: impl EntryListener<K, V>, include_value: bool, key_data: Option<HeapData>, predicate_data: Option<HeapData>) -> Uuid {
+    let flags = EntryEventType::flags(&listener.event_types());
+    let codec = EntryListenerCodec::new(self.base.name.clone(), include_value, flags, key_data, predicate_data);
     let listener = Arc::new(listener);
-    let cluster_service = self.base.cluster_service.clone();
     let base = self.base.clone();
-    self.base.listener_service.register_listener(EntryListenerCodec::new(self.base.name.clone(), true, FLAGS), {
+    self.base.listener_service.register_listener(codec.clone(), {
       move |mut client_message| {
+        let codec = codec.clone();
         let listener = listener.clone();
-        let cluster_service = cluster_service.clone();
         let base = base.clone();
         Box::pin(async move {
-          MapAddEntryListenerCodec::handle(&mut client_message, Some(Box::pin({
-            move |key, value, old_value, merging_value, event_type, uuid, number_of_affeced_entries| {
+          codec.handle(&mut client_message, Some(Box::pin({
+            move |key, value, old_value, merging_value, event_type, uuid, number_of_affected_entries| {
               let listener = listener.clone();
               let base = base.clone();
-              let cluster_service = cluster_service.clone();
               Box::pin(async move {
-                listener.call((EntryEvent::new(
-                  base.name.clone(),
-                  if let Some(key) = key { Some(*base.serialization_service.to_object(key).await) } else { None },
-                  if let Some(value) = value { Some(*base.serialization_service.to_object(value).await) } else { None },
-                  if let Some(old_value) = old_value { Some(*base.serialization_service.to_object(old_value).await) } else { None },
-                  if let Some(merging_value) = merging_value { Some(*base.serialization_service.to_object(merging_value).await) } else { None },
-                  cluster_service.get_member(uuid).await,
-                ), )).await;
+                dispatch_entry_event(&base, &*listener, key, value, old_value, merging_value, event_type, uuid, number_of_affected_entries).await;
               })
             }
           }))).await;
         })
       }
-    }).await;
+    }).await
+  }
+
+  pub async fn put(&self, key: impl Into<K>, value: impl Into<V>) -> Option<V> {
+    let key = key.into();
+    let value = value.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let value_data = self.base.to_data(Box::new(value));
+    let previous = self.put_internal(key_data, value_data, None).await;
+    self.to_value(previous).await
+  }
+
+  pub async fn put_with_ttl(&self, key: impl Into<K>, value: impl Into<V>, ttl: Duration) -> Option<V> {
+    let key = key.into();
+    let value = value.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let value_data = self.base.to_data(Box::new(value));
+    let previous = self.put_internal(key_data, value_data, Some(ttl)).await;
+    self.to_value(previous).await
+  }
+
+  pub async fn put_if_absent(&self, key: impl Into<K>, value: impl Into<V>) -> Option<V> {
+    let key = key.into();
+    let value = value.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let value_data = self.base.to_data(Box::new(value));
+    let previous = self.put_if_absent_internal(key_data, value_data, None).await;
+    self.to_value(previous).await
+  }
+
+  pub async fn put_if_absent_with_ttl(&self, key: impl Into<K>, value: impl Into<V>, ttl: Duration) -> Option<V> {
+    let key = key.into();
+    let value = value.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let value_data = self.base.to_data(Box::new(value));
+    let previous = self.put_if_absent_internal(key_data, value_data, Some(ttl)).await;
+    self.to_value(previous).await
+  }
+
+  pub async fn set(&self, key: impl Into<K>, value: impl Into<V>) {
+    let key = key.into();
+    let value = value.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let value_data = self.base.to_data(Box::new(value));
+    self.set_internal(key_data, value_data, None).await;
+  }
+
+  pub async fn set_with_ttl(&self, key: impl Into<K>, value: impl Into<V>, ttl: Duration) {
+    let key = key.into();
+    let value = value.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let value_data = self.base.to_data(Box::new(value));
+    self.set_internal(key_data, value_data, Some(ttl)).await;
+  }
+
+  pub async fn put_with_max_idle(&self, key: impl Into<K>, value: impl Into<V>, ttl: Option<Duration>, max_idle: Duration) -> Option<V> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.base.to_data(Box::new(value.into()));
+    let previous = self.put_with_max_idle_internal(key_data, value_data, ttl, max_idle).await;
+    self.to_value(previous).await
+  }
+
+  pub async fn put_if_absent_with_max_idle(&self, key: impl Into<K>, value: impl Into<V>, ttl: Option<Duration>, max_idle: Duration) -> Option<V> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.base.to_data(Box::new(value.into()));
+    let previous = self.put_if_absent_with_max_idle_internal(key_data, value_data, ttl, max_idle).await;
+    self.to_value(previous).await
+  }
+
+  pub async fn set_with_max_idle(&self, key: impl Into<K>, value: impl Into<V>, ttl: Option<Duration>, max_idle: Duration) {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.base.to_data(Box::new(value.into()));
+    self.set_with_max_idle_internal(key_data, value_data, ttl, max_idle).await;
+  }
+
+  /// Like `set`, but the entry is never written to the map store.
+  pub async fn put_transient(&self, key: impl Into<K>, value: impl Into<V>) {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.base.to_data(Box::new(value.into()));
+    self.put_transient_internal(key_data, value_data, None).await;
+  }
+
+  pub async fn put_transient_with_ttl(&self, key: impl Into<K>, value: impl Into<V>, ttl: Duration) {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.base.to_data(Box::new(value.into()));
+    self.put_transient_internal(key_data, value_data, Some(ttl)).await;
+  }
+
+  pub async fn put_transient_with_max_idle(&self, key: impl Into<K>, value: impl Into<V>, ttl: Option<Duration>, max_idle: Duration) {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.base.to_data(Box::new(value.into()));
+    self.put_transient_with_max_idle_internal(key_data, value_data, ttl, max_idle).await;
+  }
+
+  /// Changes the ttl of an existing entry, returns false if there is no entry for the key.
+  pub async fn set_ttl(&self, key: impl Into<K>, ttl: Duration) -> bool {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.set_ttl_internal(key_data, ttl).await
   }
 
+  pub async fn get_entry_view(&self, key: impl Into<K>) -> Option<EntryView<K, V>> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let response = self.get_entry_view_internal(key_data).await;
+    if let Some(entry_view) = response.response {
+      let key = self.base.serialization_service.to_object::<K>(entry_view.key.clone()).await;
+      let value = self.base.serialization_service.to_object::<V>(entry_view.value.clone()).await;
+      let mut entry_view = entry_view.map(*key, *value);
+      entry_view.max_idle = response.max_idle;
+      Some(entry_view)
+    } else {
+      None
+    }
+  }
 
-  pub async fn put(&self, key: impl Into<K>, value: impl Into<V>) {
+  pub async fn try_put(&self, key: impl Into<K>, value: impl Into<V>, timeout: Duration) -> bool {
     let key = key.into();
     let value = value.into();
     let key_data = self.base.to_data(Box::new(key));
     let value_data = self.base.to_data(Box::new(value));
-    self.put_internal(key_data, value_data, None).await;
+    self.try_put_internal(key_data, value_data, timeout).await
   }
 
-  pub async fn put_with_ttl(&self, key: impl Into<K>, value: impl Into<V>, ttl: Duration) {
+  pub async fn replace(&self, key: impl Into<K>, value: impl Into<V>) -> Option<V> {
     let key = key.into();
     let value = value.into();
     let key_data = self.base.to_data(Box::new(key));
     let value_data = self.base.to_data(Box::new(value));
-    self.put_internal(key_data, value_data, Some(ttl)).await;
+    let previous = self.replace_internal(key_data, value_data).await;
+    self.to_value(previous).await
+  }
+
+  pub async fn replace_if_same(&self, key: impl Into<K>, old_value: impl Into<V>, new_value: impl Into<V>) -> bool {
+    let key = key.into();
+    let old_value = old_value.into();
+    let new_value = new_value.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let old_value_data = self.base.to_data(Box::new(old_value));
+    let new_value_data = self.base.to_data(Box::new(new_value));
+    self.replace_if_same_internal(key_data, old_value_data, new_value_data).await
   }
 
-  pub async fn remove(&self, key: impl Into<K>) {
+  pub async fn remove(&self, key: impl Into<K>) -> Option<V> {
     let key: K = key.into();
     let key_data = self.base.to_data(Box::new(key.clone()));
-    self.remove_internal(key_data).await;
+    let previous = self.remove_internal(key_data).await;
+    self.to_value(previous).await
+  }
+
+  pub async fn remove_if_same(&self, key: impl Into<K>, value: impl Into<V>) -> bool {
+    let key = key.into();
+    let value = value.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let value_data = self.base.to_data(Box::new(value));
+    self.remove_if_same_internal(key_data, value_data).await
+  }
+
+  pub async fn try_remove(&self, key: impl Into<K>, timeout: Duration) -> bool {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    self.try_remove_internal(key_data, timeout).await
+  }
+
+  pub async fn delete(&self, key: impl Into<K>) {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    self.delete_internal(key_data).await;
+  }
+
+  /// Acquires the lock for the current `ThreadId::scope`.
+  pub async fn lock(&self, key: impl Into<K>) -> Result<(), MissingThreadIdScopeError> {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    self.lock_internal(key_data, None, ThreadId::scoped()?).await;
+    Ok(())
+  }
+
+  pub async fn lock_with_lease(&self, key: impl Into<K>, lease: Duration) -> Result<(), MissingThreadIdScopeError> {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    self.lock_internal(key_data, Some(lease), ThreadId::scoped()?).await;
+    Ok(())
+  }
+
+  pub async fn lock_guard(&self, key: impl Into<K>) -> Result<LockGuard, MissingThreadIdScopeError> {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let thread_id = ThreadId::scoped()?;
+    self.lock_internal(key_data.clone(), None, thread_id).await;
+    Ok(self.lock_guard_internal(key_data, thread_id))
+  }
+
+  pub async fn try_lock(&self, key: impl Into<K>, timeout: Duration, lease: Option<Duration>) -> Result<bool, MissingThreadIdScopeError> {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    Ok(self.try_lock_internal(key_data, timeout, lease, ThreadId::scoped()?).await)
+  }
+
+  pub async fn try_lock_guard(&self, key: impl Into<K>, timeout: Duration, lease: Option<Duration>) -> Result<Option<LockGuard>, MissingThreadIdScopeError> {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    let thread_id = ThreadId::scoped()?;
+    if self.try_lock_internal(key_data.clone(), timeout, lease, thread_id).await {
+      Ok(Some(self.lock_guard_internal(key_data, thread_id)))
+    } else {
+      Ok(None)
+    }
+  }
+
+  pub async fn unlock(&self, key: impl Into<K>) -> Result<(), MissingThreadIdScopeError> {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    self.unlock_internal(key_data, ThreadId::scoped()?).await;
+    Ok(())
+  }
+
+  pub async fn is_locked(&self, key: impl Into<K>) -> bool {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    self.is_locked_internal(key_data).await
+  }
+
+  pub async fn force_unlock(&self, key: impl Into<K>) {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key));
+    self.force_unlock_internal(key_data).await;
+  }
+
+  fn lock_guard_internal(&self, key_data: HeapData, thread_id: i64) -> LockGuard {
+    let this = self.clone();
+    LockGuard::new(async move {
+      this.unlock_internal(key_data, thread_id).await;
+    })
+  }
+
+  async fn lock_internal(&self, key_data: HeapData, lease: Option<Duration>, thread_id: i64) {
+    let reference_id = self.base.lock_reference_id_generator.get_next_reference_id();
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapLockCodec::encode_request(&name, &key_data, &thread_id, &lease.map(|lease| lease.as_millis() as i64).unwrap_or(-1), &reference_id).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  async fn try_lock_internal(&self, key_data: HeapData, timeout: Duration, lease: Option<Duration>, thread_id: i64) -> bool {
+    let reference_id = self.base.lock_reference_id_generator.get_next_reference_id();
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapTryLockCodec::encode_request(&name, &key_data, &thread_id, &lease.map(|lease| lease.as_millis() as i64).unwrap_or(-1), &(timeout.as_millis() as i64), &reference_id).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapTryLockCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn unlock_internal(&self, key_data: HeapData, thread_id: i64) {
+    let reference_id = self.base.lock_reference_id_generator.get_next_reference_id();
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapUnlockCodec::encode_request(&name, &key_data, &thread_id, &reference_id).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  async fn is_locked_internal(&self, key_data: HeapData) -> bool {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapIsLockedCodec::encode_request(&name, &key_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapIsLockedCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn force_unlock_internal(&self, key_data: HeapData) {
+    let reference_id = self.base.lock_reference_id_generator.get_next_reference_id();
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapForceUnlockCodec::encode_request(&name, &key_data, &reference_id).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  async fn to_value(&self, data: Option<HeapData>) -> Option<V> {
+    if let Some(data) = data {
+      Some(*self.base.serialization_service.to_object::<V>(data).await)
+    } else {
+      None
+    }
   }
 
-  pub async fn remove_internal(&self, key_data: HeapData) {
+  async fn remove_internal(&self, key_data: HeapData) -> Option<HeapData> {
     self.base.encode_invoke_on_key(
       key_data.clone(),
       Box::pin({
         move |name| Box::pin({
           let key_data = key_data.clone();
           async move {
-            MapRemoveCodec::encode_request(&name, &key_data, &0).await
+            MapRemoveCodec::encode_request(&name, &key_data, &ThreadId::current()).await
           }
         })
       }),
       Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapRemoveCodec::decode_response(&mut response).await)) })),
-    ).await;
+    ).await
+  }
+
+  async fn remove_if_same_internal(&self, key_data: HeapData, value_data: HeapData) -> bool {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapRemoveIfSameCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapRemoveIfSameCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn try_remove_internal(&self, key_data: HeapData, timeout: Duration) -> bool {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapTryRemoveCodec::encode_request(&name, &key_data, &ThreadId::current(), &(timeout.as_millis() as i64)).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapTryRemoveCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn delete_internal(&self, key_data: HeapData) {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapDeleteCodec::encode_request(&name, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
   }
 
-  async fn put_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>) {
+  async fn put_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>) -> Option<HeapData> {
     self.base.encode_invoke_on_key(
       key_data.clone(),
       Box::pin({
@@ -155,18 +618,1012 @@ impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static
           let key_data = key_data.clone();
           let value_data = value_data.clone();
           async move {
-            MapPutCodec::encode_request(&name, &key_data, &value_data, &0, &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1)).await
+            MapPutCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1)).await
           }
         })
       }),
       Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapPutCodec::decode_response(&mut response).await)) })),
-    ).await;
+    ).await
   }
 
-  pub async fn get(&self, key: impl Into<K>) -> Option<V> {
-    let key = key.into();
-    let key_data = self.base.to_data(Box::new(key.clone()));
-    self.get_internal(key_data).await.map(|data| *data)
+  async fn put_if_absent_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>) -> Option<HeapData> {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapPutIfAbsentCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1)).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapPutIfAbsentCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn set_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>) {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapSetCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1)).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  async fn put_with_max_idle_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>, max_idle: Duration) -> Option<HeapData> {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapPutWithMaxIdleCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1), &(max_idle.as_millis() as i64)).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapPutWithMaxIdleCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn put_if_absent_with_max_idle_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>, max_idle: Duration) -> Option<HeapData> {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapPutIfAbsentWithMaxIdleCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1), &(max_idle.as_millis() as i64)).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapPutIfAbsentWithMaxIdleCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn set_with_max_idle_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>, max_idle: Duration) {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapSetWithMaxIdleCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1), &(max_idle.as_millis() as i64)).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  async fn put_transient_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>) {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapPutTransientCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1)).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  async fn put_transient_with_max_idle_internal(&self, key_data: HeapData, value_data: HeapData, ttl: Option<Duration>, max_idle: Duration) {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapPutTransientWithMaxIdleCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &ttl.map(|ttl| ttl.as_millis() as i64).unwrap_or(-1), &(max_idle.as_millis() as i64)).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  async fn set_ttl_internal(&self, key_data: HeapData, ttl: Duration) -> bool {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapSetTtlCodec::encode_request(&name, &key_data, &(ttl.as_millis() as i64)).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapSetTtlCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn get_entry_view_internal(&self, key_data: HeapData) -> MapGetEntryViewResponseParams {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapGetEntryViewCodec::encode_request(&name, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapGetEntryViewCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn try_put_internal(&self, key_data: HeapData, value_data: HeapData, timeout: Duration) -> bool {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapTryPutCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current(), &(timeout.as_millis() as i64)).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapTryPutCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn replace_internal(&self, key_data: HeapData, value_data: HeapData) -> Option<HeapData> {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MapReplaceCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapReplaceCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn replace_if_same_internal(&self, key_data: HeapData, old_value_data: HeapData, new_value_data: HeapData) -> bool {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let old_value_data = old_value_data.clone();
+          let new_value_data = new_value_data.clone();
+          async move {
+            MapReplaceIfSameCodec::encode_request(&name, &key_data, &old_value_data, &new_value_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapReplaceIfSameCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn get(&self, key: impl Into<K>) -> Option<V> {
+    let key = key.into();
+    let key_data = self.base.to_data(Box::new(key.clone()));
+    self.get_internal(key_data).await.map(|data| *data)
+  }
+
+  pub async fn size(&self) -> i32 {
+    self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MapSizeCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapSizeCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn is_empty(&self) -> bool {
+    self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MapIsEmptyCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapIsEmptyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_key(&self, key: impl Into<K>) -> bool {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapContainsKeyCodec::encode_request(&name, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapContainsKeyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_value(&self, value: impl Into<V>) -> bool {
+    let value_data = self.base.to_data(Box::new(value.into()));
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let value_data = value_data.clone();
+          async move {
+            MapContainsValueCodec::encode_request(&name, &value_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapContainsValueCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn key_set(&self) -> ReadOnlyLazyList<K> {
+    let keys_data = self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MapKeySetCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapKeySetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(keys_data, self.base.serialization_service.clone(), |serialization_service, key_data| {
+      Box::pin(async move { *serialization_service.to_object::<K>(key_data).await })
+    })
+  }
+
+  pub async fn values(&self) -> ReadOnlyLazyList<V> {
+    let values_data = self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MapValuesCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapValuesCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(values_data, self.base.serialization_service.clone(), |serialization_service, value_data| {
+      Box::pin(async move { *serialization_service.to_object::<V>(value_data).await })
+    })
+  }
+
+  pub async fn entry_set(&self) -> ReadOnlyLazyList<(K, V), (HeapData, HeapData)> {
+    let entries_data = self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MapEntrySetCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapEntrySetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(entries_data, self.base.serialization_service.clone(), |serialization_service, (key_data, value_data)| {
+      Box::pin(async move {
+        let key = serialization_service.to_object::<K>(key_data).await;
+        let value = serialization_service.to_object::<V>(value_data).await;
+        (*key, *value)
+      })
+    })
+  }
+
+  pub async fn clear(&self) {
+    self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MapClearCodec::encode_request(&name).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Evicts the entry from memory without removing it from the MapStore.
+  pub async fn evict(&self, key: impl Into<K>) -> bool {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MapEvictCodec::encode_request(&name, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapEvictCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn evict_all(&self) {
+    self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MapEvictAllCodec::encode_request(&name).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Writes all dirty entries to the MapStore when write-behind is enabled.
+  pub async fn flush(&self) {
+    self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MapFlushCodec::encode_request(&name).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn load_all(&self, replace_existing_values: bool) {
+    self.base.encode_invoke(
+      Box::pin(move |name| Box::pin(async move { MapLoadAllCodec::encode_request(&name, &replace_existing_values).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn load_given_keys(&self, keys: Vec<K>, replace_existing_values: bool) {
+    let keys_data: Vec<HeapData> = keys.into_iter().map(|key| self.base.to_data(Box::new(key))).collect();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let keys_data = keys_data.clone();
+          async move {
+            MapLoadGivenKeysCodec::encode_request(&name, &keys_data, &replace_existing_values).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Registers a MapInterceptor on the members and returns its registration
+  /// id. The interceptor runs on the members, see [`ServerSideFunction`].
+  pub async fn add_interceptor<I: Serializable + Send + Sync + 'static>(&self, interceptor: I) -> String {
+    let interceptor_data = self.base.to_data(Box::new(interceptor));
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let interceptor_data = interceptor_data.clone();
+          async move {
+            MapAddInterceptorCodec::encode_request(&name, &interceptor_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapAddInterceptorCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn remove_interceptor(&self, id: impl ToString) -> bool {
+    let id = id.to_string();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let id = id.clone();
+          async move {
+            MapRemoveInterceptorCodec::encode_request(&name, &id).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapRemoveInterceptorCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn get_all(&self, keys: Vec<K>) -> HashMap<K, V> where K: Eq + Hash {
+    let mut partitions: HashMap<i32, Vec<HeapData>> = HashMap::new();
+    for key in keys {
+      let key_data = self.base.to_data(Box::new(key));
+      let partition_id = self.base.partition_service.get_partition_id(key_data.clone()).await;
+      partitions.entry(partition_id).or_default().push(key_data);
+    }
+
+    let responses = join_all(partitions.into_iter().map(|(partition_id, keys_data)| {
+      self.get_all_internal(partition_id, keys_data)
+    })).await;
+
+    let mut result = HashMap::new();
+    for (key_data, value_data) in responses.into_iter().flatten() {
+      let key = self.base.serialization_service.to_object::<K>(key_data).await;
+      let value = self.base.serialization_service.to_object::<V>(value_data).await;
+      result.insert(*key, *value);
+    }
+    result
+  }
+
+  async fn get_all_internal(&self, partition_id: i32, keys_data: Vec<HeapData>) -> Vec<(HeapData, HeapData)> {
+    self.base.encode_invoke_on_partition(
+      partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let keys_data = keys_data.clone();
+          async move {
+            MapGetAllCodec::encode_request(&name, &keys_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapGetAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn put_all(&self, entries: impl IntoIterator<Item=(K, V)>) {
+    let mut partitions: HashMap<i32, Vec<(HeapData, HeapData)>> = HashMap::new();
+    for (key, value) in entries {
+      let key_data = self.base.to_data(Box::new(key));
+      let value_data = self.base.to_data(Box::new(value));
+      let partition_id = self.base.partition_service.get_partition_id(key_data.clone()).await;
+      partitions.entry(partition_id).or_default().push((key_data, value_data));
+    }
+
+    join_all(partitions.into_iter().map(|(partition_id, entries_data)| {
+      self.put_all_internal(partition_id, entries_data)
+    })).await;
+  }
+
+  async fn put_all_internal(&self, partition_id: i32, entries_data: Vec<(HeapData, HeapData)>) {
+    self.base.encode_invoke_on_partition(
+      partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let entries_data = entries_data.clone();
+          async move {
+            MapPutAllCodec::encode_request(&name, &entries_data, &true).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn remove_all(&self, predicate: Predicate) {
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let predicate_data = predicate_data.clone();
+          async move {
+            MapRemoveAllCodec::encode_request(&name, &predicate_data).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn key_set_with_predicate(&self, predicate: Predicate) -> Vec<K> {
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    let keys_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let predicate_data = predicate_data.clone();
+          async move {
+            MapKeySetWithPredicateCodec::encode_request(&name, &predicate_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapKeySetWithPredicateCodec::decode_response(&mut response).await)) })),
+    ).await;
+    let mut result = vec![];
+    for key_data in keys_data {
+      result.push(*self.base.serialization_service.to_object::<K>(key_data).await);
+    }
+    result
+  }
+
+  pub async fn values_with_predicate(&self, predicate: Predicate) -> Vec<V> {
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    let values_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let predicate_data = predicate_data.clone();
+          async move {
+            MapValuesWithPredicateCodec::encode_request(&name, &predicate_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapValuesWithPredicateCodec::decode_response(&mut response).await)) })),
+    ).await;
+    let mut result = vec![];
+    for value_data in values_data {
+      result.push(*self.base.serialization_service.to_object::<V>(value_data).await);
+    }
+    result
+  }
+
+  pub async fn entry_set_with_predicate(&self, predicate: Predicate) -> Vec<(K, V)> {
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    let entries_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let predicate_data = predicate_data.clone();
+          async move {
+            MapEntriesWithPredicateCodec::encode_request(&name, &predicate_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapEntriesWithPredicateCodec::decode_response(&mut response).await)) })),
+    ).await;
+    let mut result = vec![];
+    for (key_data, value_data) in entries_data {
+      let key = self.base.serialization_service.to_object::<K>(key_data).await;
+      let value = self.base.serialization_service.to_object::<V>(value_data).await;
+      result.push((*key, *value));
+    }
+    result
+  }
+
+  pub async fn add_index(&self, index_config: IndexConfig) -> Result<(), InvalidIndexConfigError> {
+    let index_config = index_config.normalize(self.base.name.as_str())?;
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let index_config = index_config.clone();
+          async move {
+            MapAddIndexCodec::encode_request(&name, &index_config).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await;
+    Ok(())
+  }
+
+  pub async fn aggregate<R: QueryResult>(&self, aggregator: Aggregator<R>) -> Result<R, QueryResultTypeError> {
+    let aggregator_data = self.base.to_data(Box::new(aggregator.into_aggregation()));
+    let result_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let aggregator_data = aggregator_data.clone();
+          async move {
+            MapAggregateCodec::encode_request(&name, &aggregator_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapAggregateCodec::decode_response(&mut response).await)) })),
+    ).await;
+    R::from_data(&self.base.serialization_service, result_data)
+  }
+
+  pub async fn aggregate_with_predicate<R: QueryResult>(&self, aggregator: Aggregator<R>, predicate: Predicate) -> Result<R, QueryResultTypeError> {
+    let aggregator_data = self.base.to_data(Box::new(aggregator.into_aggregation()));
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    let result_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let aggregator_data = aggregator_data.clone();
+          let predicate_data = predicate_data.clone();
+          async move {
+            MapAggregateWithPredicateCodec::encode_request(&name, &aggregator_data, &predicate_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapAggregateWithPredicateCodec::decode_response(&mut response).await)) })),
+    ).await;
+    R::from_data(&self.base.serialization_service, result_data)
+  }
+
+  pub async fn project<P: QueryResult>(&self, projection: Projection) -> Result<Vec<P>, QueryResultTypeError> {
+    let projection_data = self.base.to_data(Box::new(projection));
+    let results_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let projection_data = projection_data.clone();
+          async move {
+            MapProjectCodec::encode_request(&name, &projection_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapProjectCodec::decode_response(&mut response).await)) })),
+    ).await;
+    results_data.into_iter()
+      .map(|result_data| P::from_data(&self.base.serialization_service, result_data))
+      .collect()
+  }
+
+  pub async fn project_with_predicate<P: QueryResult>(&self, projection: Projection, predicate: Predicate) -> Result<Vec<P>, QueryResultTypeError> {
+    let projection_data = self.base.to_data(Box::new(projection));
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    let results_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let projection_data = projection_data.clone();
+          let predicate_data = predicate_data.clone();
+          async move {
+            MapProjectWithPredicateCodec::encode_request(&name, &projection_data, &predicate_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapProjectWithPredicateCodec::decode_response(&mut response).await)) })),
+    ).await;
+    results_data.into_iter()
+      .map(|result_data| P::from_data(&self.base.serialization_service, result_data))
+      .collect()
+  }
+
+  pub async fn execute_on_key<EP: EntryProcessor>(&self, key: impl Into<K>, entry_processor: EP) -> Option<EP::Output> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let entry_processor_data = self.base.to_data(Box::new(entry_processor));
+    let result_data = self.execute_on_key_internal(key_data, entry_processor_data).await;
+    self.to_result::<EP>(result_data).await
+  }
+
+  /// Sends the processor right away; the returned handle resolves once the
+  /// member answered, independent of whether the caller awaits it.
+  pub fn submit_to_key<EP: EntryProcessor>(&self, key: impl Into<K>, entry_processor: EP) -> JoinHandle<Option<EP::Output>> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let entry_processor_data = self.base.to_data(Box::new(entry_processor));
+    let thread_id = ThreadId::current();
+    let proxy = self.clone();
+    tokio::spawn(async move {
+      let result_data = proxy.submit_to_key_internal(key_data, entry_processor_data, thread_id).await;
+      proxy.to_result::<EP>(result_data).await
+    })
+  }
+
+  pub async fn execute_on_keys<EP: EntryProcessor>(&self, keys: Vec<K>, entry_processor: EP) -> HashMap<K, EP::Output> where K: Eq + Hash {
+    if keys.is_empty() {
+      return HashMap::new();
+    }
+    let keys_data: Vec<HeapData> = keys.into_iter().map(|key| self.base.to_data(Box::new(key))).collect();
+    let entry_processor_data = self.base.to_data(Box::new(entry_processor));
+    let results_data = self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let entry_processor_data = entry_processor_data.clone();
+          let keys_data = keys_data.clone();
+          async move {
+            MapExecuteOnKeysCodec::encode_request(&name, &entry_processor_data, &keys_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapExecuteOnKeysCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_results::<EP>(results_data).await
+  }
+
+  pub async fn execute_on_entries<EP: EntryProcessor>(&self, entry_processor: EP, predicate: Option<Predicate>) -> HashMap<K, EP::Output> where K: Eq + Hash {
+    let entry_processor_data = self.base.to_data(Box::new(entry_processor));
+    let results_data = if let Some(predicate) = predicate {
+      let predicate_data = self.base.to_data(Box::new(predicate));
+      self.base.encode_invoke(
+        Box::pin({
+          move |name| Box::pin({
+            let entry_processor_data = entry_processor_data.clone();
+            let predicate_data = predicate_data.clone();
+            async move {
+              MapExecuteWithPredicateCodec::encode_request(&name, &entry_processor_data, &predicate_data).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapExecuteWithPredicateCodec::decode_response(&mut response).await)) })),
+      ).await
+    } else {
+      self.base.encode_invoke(
+        Box::pin({
+          move |name| Box::pin({
+            let entry_processor_data = entry_processor_data.clone();
+            async move {
+              MapExecuteOnAllKeysCodec::encode_request(&name, &entry_processor_data).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapExecuteOnAllKeysCodec::decode_response(&mut response).await)) })),
+      ).await
+    };
+    self.to_results::<EP>(results_data).await
+  }
+
+  async fn to_result<EP: EntryProcessor>(&self, result_data: Option<HeapData>) -> Option<EP::Output> {
+    if let Some(result_data) = result_data {
+      Some(*self.base.serialization_service.to_object::<EP::Output>(result_data).await)
+    } else {
+      None
+    }
+  }
+
+  async fn to_results<EP: EntryProcessor>(&self, results_data: Vec<(HeapData, HeapData)>) -> HashMap<K, EP::Output> where K: Eq + Hash {
+    let mut result = HashMap::new();
+    for (key_data, result_data) in results_data {
+      let key = self.base.serialization_service.to_object::<K>(key_data).await;
+      let value = self.base.serialization_service.to_object::<EP::Output>(result_data).await;
+      result.insert(*key, *value);
+    }
+    result
+  }
+
+  async fn execute_on_key_internal(&self, key_data: HeapData, entry_processor_data: HeapData) -> Option<HeapData> {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let entry_processor_data = entry_processor_data.clone();
+          async move {
+            MapExecuteOnKeyCodec::encode_request(&name, &entry_processor_data, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapExecuteOnKeyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn submit_to_key_internal(&self, key_data: HeapData, entry_processor_data: HeapData, thread_id: i64) -> Option<HeapData> {
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let entry_processor_data = entry_processor_data.clone();
+          async move {
+            MapSubmitToKeyCodec::encode_request(&name, &entry_processor_data, &key_data, &thread_id).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapSubmitToKeyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub fn key_set_with_paging_predicate(&self, paging_predicate: PagingPredicate) -> impl Stream<Item=Vec<K>> + Send {
+    self.paging_stream(paging_predicate, IterationType::Key, |proxy: Self, holder| async move {
+      let response = proxy.base.encode_invoke(
+        Box::pin({
+          move |name| Box::pin({
+            let holder = holder.clone();
+            async move {
+              MapKeySetWithPagingPredicateCodec::encode_request(&name, &holder).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapKeySetWithPagingPredicateCodec::decode_response(&mut response).await)) })),
+      ).await;
+      let mut keys = vec![];
+      for key_data in response.response {
+        keys.push(*proxy.base.serialization_service.to_object::<K>(key_data).await);
+      }
+      (keys, response.anchor_data_list)
+    })
+  }
+
+  pub fn values_with_paging_predicate(&self, paging_predicate: PagingPredicate) -> impl Stream<Item=Vec<V>> + Send {
+    self.paging_stream(paging_predicate, IterationType::Value, |proxy: Self, holder| async move {
+      let response = proxy.base.encode_invoke(
+        Box::pin({
+          move |name| Box::pin({
+            let holder = holder.clone();
+            async move {
+              MapValuesWithPagingPredicateCodec::encode_request(&name, &holder).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapValuesWithPagingPredicateCodec::decode_response(&mut response).await)) })),
+      ).await;
+      let mut values = vec![];
+      for value_data in response.response {
+        values.push(*proxy.base.serialization_service.to_object::<V>(value_data).await);
+      }
+      (values, response.anchor_data_list)
+    })
+  }
+
+  pub fn entry_set_with_paging_predicate(&self, paging_predicate: PagingPredicate) -> impl Stream<Item=Vec<(K, V)>> + Send {
+    self.paging_stream(paging_predicate, IterationType::Entry, |proxy: Self, holder| async move {
+      let response = proxy.base.encode_invoke(
+        Box::pin({
+          move |name| Box::pin({
+            let holder = holder.clone();
+            async move {
+              MapEntriesWithPagingPredicateCodec::encode_request(&name, &holder).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapEntriesWithPagingPredicateCodec::decode_response(&mut response).await)) })),
+      ).await;
+      let mut entries = vec![];
+      for (key_data, value_data) in response.response {
+        let key = proxy.base.serialization_service.to_object::<K>(key_data).await;
+        let value = proxy.base.serialization_service.to_object::<V>(value_data).await;
+        entries.push((*key, *value));
+      }
+      (entries, response.anchor_data_list)
+    })
+  }
+
+  /// Fetches one page per poll, starting at the predicate's current page.
+  /// The stream ends after the first page that is not full.
+  fn paging_stream<T, F, Fut>(&self, mut paging_predicate: PagingPredicate, iteration_type: IterationType, fetch_page: F) -> impl Stream<Item=Vec<T>> + Send
+    where
+      T: Send,
+      F: Fn(Self, PagingPredicateHolder) -> Fut + Send + 'static,
+      Fut: Future<Output=(Vec<T>, AnchorDataListHolder)> + Send {
+    paging_predicate.set_iteration_type(iteration_type);
+    stream::unfold(Some((self.clone(), paging_predicate, fetch_page)), |state| async move {
+      let (proxy, mut paging_predicate, fetch_page) = state?;
+      let holder = paging_predicate.to_holder(&proxy.base.serialization_service);
+      let (page, anchor_data_list) = fetch_page(proxy.clone(), holder).await;
+      if page.is_empty() {
+        return None;
+      }
+      paging_predicate.set_anchor_list(anchor_data_list);
+      if page.len() < paging_predicate.get_page_size() as usize {
+        return Some((page, None));
+      }
+      paging_predicate.next_page();
+      Some((page, Some((proxy, paging_predicate, fetch_page))))
+    })
+  }
+
+  /// Walks the map partition by partition, fetching `batch_size` keys per
+  /// request. The iteration pointers returned by the member keep the cursor
+  /// valid while the partition is resized or migrated.
+  pub fn iter_keys(&self, batch_size: NonZeroU32) -> impl Stream<Item=K> + Send {
+    let batch_size = i32::try_from(batch_size.get()).unwrap_or(i32::MAX);
+    self.iterate(move |proxy: Self, partition_id, iteration_pointers| async move {
+      let response = proxy.base.encode_invoke_on_partition(
+        partition_id,
+        Box::pin({
+          move |name| Box::pin({
+            let iteration_pointers = iteration_pointers.clone();
+            async move {
+              MapFetchKeysCodec::encode_request(&name, &iteration_pointers, &batch_size).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapFetchKeysCodec::decode_response(&mut response).await)) })),
+      ).await;
+      let mut keys = vec![];
+      for key_data in response.keys {
+        keys.push(*proxy.base.serialization_service.to_object::<K>(key_data).await);
+      }
+      (keys, response.iteration_pointers)
+    })
+  }
+
+  pub fn iter_entries(&self, batch_size: NonZeroU32) -> impl Stream<Item=(K, V)> + Send {
+    let batch_size = i32::try_from(batch_size.get()).unwrap_or(i32::MAX);
+    self.iterate(move |proxy: Self, partition_id, iteration_pointers| async move {
+      let response = proxy.base.encode_invoke_on_partition(
+        partition_id,
+        Box::pin({
+          move |name| Box::pin({
+            let iteration_pointers = iteration_pointers.clone();
+            async move {
+              MapFetchEntriesCodec::encode_request(&name, &iteration_pointers, &batch_size).await
+            }
+          })
+        }),
+        Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapFetchEntriesCodec::decode_response(&mut response).await)) })),
+      ).await;
+      let mut entries = vec![];
+      for (key_data, value_data) in response.entries {
+        let key = proxy.base.serialization_service.to_object::<K>(key_data).await;
+        let value = proxy.base.serialization_service.to_object::<V>(value_data).await;
+        entries.push((*key, *value));
+      }
+      (entries, response.iteration_pointers)
+    })
+  }
+
+  fn iterate<T, F, Fut>(&self, fetch_batch: F) -> impl Stream<Item=T> + Send
+    where
+      T: Send,
+      F: Fn(Self, i32, Vec<(i32, i32)>) -> Fut + Send + 'static,
+      Fut: Future<Output=(Vec<T>, Vec<(i32, i32)>)> + Send {
+    let initial_pointers = vec![(i32::MAX, -1)];
+    stream::unfold(Some((self.clone(), fetch_batch, 0, initial_pointers.clone(), None)), move |state| {
+      let initial_pointers = initial_pointers.clone();
+      async move {
+        let (proxy, fetch_batch, mut partition_id, mut iteration_pointers, partition_count) = state?;
+        let partition_count = match partition_count {
+          Some(partition_count) => partition_count,
+          None => *proxy.base.partition_service.partition_count.read().await,
+        };
+        while partition_id < partition_count {
+          let (batch, next_pointers) = fetch_batch(proxy.clone(), partition_id, iteration_pointers.clone()).await;
+          // a negative index on the last pointer marks the partition as exhausted
+          if next_pointers.last().map(|(index, _)| *index < 0).unwrap_or(true) {
+            partition_id += 1;
+            iteration_pointers = initial_pointers.clone();
+          } else {
+            iteration_pointers = next_pointers;
+          }
+          if !batch.is_empty() {
+            return Some((batch, Some((proxy, fetch_batch, partition_id, iteration_pointers, Some(partition_count)))));
+          }
+        }
+        None
+      }
+    }).flat_map(stream::iter)
+  }
+
+  /// Reads the event journal of every partition. One stream is returned per
+  /// partition, indexed by partition id unless resuming. A negative start
+  /// sequence reads from the oldest event still in the journal. The members
+  /// filter the events with `predicate` and, if given, send the result of
+  /// `projection` instead of the event.
+  pub async fn read_from_event_journal(&self, start_sequence: impl Into<EventJournalStart>, predicate: Option<ServerSideFunction>, projection: Option<ServerSideFunction>) -> Vec<impl Stream<Item=EventJournalMapEvent<K, V>> + Send> {
+    let resume_tokens = match start_sequence.into() {
+      EventJournalStart::Sequence(sequence) => {
+        let partition_count = *self.base.partition_service.partition_count.read().await;
+        (0..partition_count)
+          .map(|partition_id| EventJournalResumeToken { partition_id, sequence })
+          .collect()
+      }
+      EventJournalStart::Resume(resume_tokens) => resume_tokens,
+    };
+    let predicate_data = predicate.map(|predicate| predicate.to_data(&self.base.serialization_service));
+    let projection_data = projection.map(|projection| projection.to_data(&self.base.serialization_service));
+    resume_tokens
+      .into_iter()
+      .map(|resume_token| self.event_journal_stream(resume_token, predicate_data.clone(), projection_data.clone()))
+      .collect()
+  }
+
+  /// Subscribes to the journal on the first poll to clamp the start sequence
+  /// to what the journal still holds, then reads it in batches. Events the
+  /// journal overwrote before they could be read, and events the client
+  /// cannot make sense of, are counted in `lost_events` of the next event
+  /// handed out.
+  fn event_journal_stream(&self, resume_token: EventJournalResumeToken, predicate_data: Option<HeapData>, projection_data: Option<HeapData>) -> impl Stream<Item=EventJournalMapEvent<K, V>> + Send {
+    const MAX_BATCH_SIZE: i32 = 100;
+    let partition_id = resume_token.partition_id;
+    stream::unfold((self.clone(), resume_token.sequence, false, 0), move |(proxy, mut sequence, subscribed, mut lost_events)| {
+      let predicate_data = predicate_data.clone();
+      let projection_data = projection_data.clone();
+      async move {
+        if !subscribed {
+          let response = proxy.base.encode_invoke_on_partition(
+            partition_id,
+            Box::pin(|name| Box::pin(async move { MapEventJournalSubscribeCodec::encode_request(&name).await })),
+            Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapEventJournalSubscribeCodec::decode_response(&mut response).await)) })),
+          ).await;
+          if sequence >= 0 && sequence < response.oldest_sequence {
+            lost_events += response.oldest_sequence - sequence;
+          }
+          sequence = sequence.max(response.oldest_sequence).min(response.newest_sequence + 1);
+        }
+        let projected = projection_data.is_some();
+        let response = proxy.base.encode_invoke_on_partition(
+          partition_id,
+          Box::pin({
+            move |name| Box::pin({
+              let predicate_data = predicate_data.clone();
+              let projection_data = projection_data.clone();
+              async move {
+                MapEventJournalReadCodec::encode_request(&name, &sequence, &1, &MAX_BATCH_SIZE, &predicate_data, &projection_data).await
+              }
+            })
+          }),
+          Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MapEventJournalReadCodec::decode_response(&mut response).await)) })),
+        ).await;
+        // the member skips ahead to the oldest sequence if ours was overwritten
+        lost_events += response.next_seq - response.read_count as i64 - sequence;
+        let mut events = vec![];
+        for (index, item) in response.items.into_iter().enumerate() {
+          let item_sequence = response.item_seqs.as_ref()
+            .and_then(|item_seqs| item_seqs.get(index).copied())
+            .unwrap_or(sequence + index as i64);
+          match proxy.to_event_journal_map_event(item, projected, partition_id, item_sequence, lost_events).await {
+            Some(event) => {
+              events.push(event);
+              lost_events = 0;
+            }
+            None => lost_events += 1,
+          }
+        }
+        Some((events, (proxy, response.next_seq, true, lost_events)))
+      }
+    }).flat_map(stream::iter)
+  }
+
+  /// Returns `None` for events of an unknown type or without a key.
+  async fn to_event_journal_map_event(&self, item: HeapData, projected: bool, partition_id: i32, sequence: i64, lost_events: i64) -> Option<EventJournalMapEvent<K, V>> {
+    if projected {
+      return Some(EventJournalMapEvent {
+        key: None,
+        new_value: Some(*self.base.serialization_service.to_object::<V>(item).await),
+        old_value: None,
+        event_type: None,
+        partition_id,
+        sequence,
+        lost_events,
+      });
+    }
+    let event = *self.base.serialization_service.to_identified::<InternalEventJournalMapEvent>(item);
+    let event_type = EntryEventType::from_id(event.event_type)?;
+    Some(EventJournalMapEvent {
+      key: Some(*self.base.serialization_service.to_object::<K>(event.key?).await),
+      new_value: self.to_value(event.new_value).await,
+      old_value: self.to_value(event.old_value).await,
+      event_type: Some(event_type),
+      partition_id,
+      sequence,
+      lost_events,
+    })
   }
 
   pub fn to_weak_map(self) -> WeakMapProxy<K, V> where K: Eq + PartialEq + Hash {
@@ -183,7 +1640,7 @@ impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static
         let key_data = key_data.clone();
 
         Box::pin(async move {
-          MapGetCodec::encode_request(&value, &key_data, &0).await
+          MapGetCodec::encode_request(&value, &key_data, &ThreadId::current()).await
         })
       })
     }, Box::pin({
@@ -230,7 +1687,7 @@ impl<K: Send + Sync + Serializable + 'static, V: Send + Sync + Serializable + 's
 impl<K: Send + Sync + Clone + Serializable + 'static, V: Send + Sync + Clone + Serializable + 'static> NestedStateInsert<K, V> for MapProxy<K, V> {
   fn insert<'a>(&'a mut self, key: impl Into<K> + Send + Sync + 'a, value: impl Into<V> + Send + Sync + 'a) -> Pin<Box<dyn Future<Output=()> + Send + Sync + 'a>> {
     Box::pin(async move {
-      self.put(key, value).await
+      self.put(key, value).await;
     })
   }
 }
\ No newline at end of file
@@ -15,12 +15,26 @@ pub mod manager;
 pub mod map_proxy;
 pub mod base;
 pub mod entry_event;
+pub mod entry_listener;
+pub mod entry_processor;
+pub mod event_journal;
 pub mod event_type;
+pub mod item_event;
+pub mod item_listener;
+pub mod list_proxy;
 pub mod multimap_proxy;
+pub mod overflow_policy;
+pub mod queue_proxy;
+pub mod replicated_map_proxy;
+pub mod ringbuffer_proxy;
+pub mod server_side_function;
+pub mod set_proxy;
 pub mod weak_map_proxy;
 pub mod strong_map_proxy;
 pub mod registry_proxy;
 pub mod weak_registry_proxy;
+pub mod lock_guard;
+pub mod lock_reference_id_generator;
 
 pub trait Proxy: ProxyBaseLogic + 'static {
   const SERVICE_NAME: &'static str;
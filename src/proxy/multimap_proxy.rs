@@ -5,17 +5,45 @@ use std::mem::transmute;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
+use futures::future::join_all;
 use lazy_static::lazy_static;
 use tokio::sync::RwLock;
+use uuid::Uuid;
+use crate::codec::multi_map_add_entry_listener_codec::MultiMapAddEntryListenerCodec;
+use crate::codec::multi_map_add_entry_listener_to_key_codec::MultiMapAddEntryListenerToKeyCodec;
+use crate::codec::multi_map_clear_codec::MultiMapClearCodec;
+use crate::codec::multi_map_contains_entry_codec::MultiMapContainsEntryCodec;
+use crate::codec::multi_map_contains_key_codec::MultiMapContainsKeyCodec;
+use crate::codec::multi_map_contains_value_codec::MultiMapContainsValueCodec;
+use crate::codec::multi_map_delete_codec::MultiMapDeleteCodec;
+use crate::codec::multi_map_entry_set_codec::MultiMapEntrySetCodec;
+use crate::codec::multi_map_force_unlock_codec::MultiMapForceUnlockCodec;
 use crate::codec::multi_map_get_codec::MultiMapGetCodec;
+use crate::codec::multi_map_is_locked_codec::MultiMapIsLockedCodec;
+use crate::codec::multi_map_key_set_codec::MultiMapKeySetCodec;
+use crate::codec::multi_map_lock_codec::MultiMapLockCodec;
+use crate::codec::multi_map_put_all_codec::MultiMapPutAllCodec;
 use crate::codec::multi_map_put_codec::MultiMapPutCodec;
+use crate::codec::multi_map_remove_codec::MultiMapRemoveCodec;
 use crate::codec::multi_map_remove_entry_codec::MultiMapRemoveEntryCodec;
+use crate::codec::multi_map_remove_entry_listener_codec::MultiMapRemoveEntryListenerCodec;
+use crate::codec::multi_map_size_codec::MultiMapSizeCodec;
+use crate::codec::multi_map_try_lock_codec::MultiMapTryLockCodec;
+use crate::codec::multi_map_unlock_codec::MultiMapUnlockCodec;
+use crate::codec::multi_map_value_count_codec::MultiMapValueCountCodec;
+use crate::codec::multi_map_values_codec::MultiMapValuesCodec;
+use crate::core::readonly_lazy_list::ReadOnlyLazyList;
+use crate::listener::message_codec::ListenerMessageCodec;
+use crate::protocol::client_message::ClientMessage;
 use crate::proxy::base::{HasProxyBase, ProxyBase};
+use crate::proxy::entry_listener::{dispatch_entry_event, EntryEventHandler, EntryListener};
+use crate::proxy::lock_guard::LockGuard;
 use crate::proxy::map_proxy::AnySend;
 use crate::proxy::Proxy;
 use crate::serialization::heap_data::HeapData;
 use crate::serialization::serializable::Serializable;
 use crate::util::maybe_future::MaybeFuture;
+use crate::util::thread_id::{MissingThreadIdScopeError, ThreadId};
 
 #[derive(Clone)]
 pub struct MultiMapProxy<K: Serializable, V: Serializable> {
@@ -23,6 +51,50 @@ pub struct MultiMapProxy<K: Serializable, V: Serializable> {
   phantom: PhantomData<(K, V)>,
 }
 
+#[derive(Clone)]
+struct MultiMapEntryListenerCodec {
+  name: String,
+  include_value: bool,
+  key: Option<HeapData>,
+}
+
+impl MultiMapEntryListenerCodec {
+  pub fn new(name: String, include_value: bool, key: Option<HeapData>) -> Self {
+    Self {
+      name,
+      include_value,
+      key,
+    }
+  }
+
+  async fn handle(&self, client_message: &mut ClientMessage, handle_entry_event: Option<EntryEventHandler>) {
+    match &self.key {
+      None => MultiMapAddEntryListenerCodec::handle(client_message, handle_entry_event).await,
+      Some(_) => MultiMapAddEntryListenerToKeyCodec::handle(client_message, handle_entry_event).await,
+    }
+  }
+}
+
+impl ListenerMessageCodec for MultiMapEntryListenerCodec {
+  fn encode_add_request<'a>(&'a self, local_only: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    match &self.key {
+      None => MultiMapAddEntryListenerCodec::encode_request(&self.name, &self.include_value, local_only),
+      Some(key) => MultiMapAddEntryListenerToKeyCodec::encode_request(&self.name, key, &self.include_value, local_only),
+    }
+  }
+
+  fn decode_add_response<'a>(&'a self, client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Uuid> + Send + Sync + 'a>> {
+    match &self.key {
+      None => MultiMapAddEntryListenerCodec::decode_response(client_message),
+      Some(_) => MultiMapAddEntryListenerToKeyCodec::decode_response(client_message),
+    }
+  }
+
+  fn encode_remove_request<'a>(&'a self, registration_id: &'a Uuid) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    MultiMapRemoveEntryListenerCodec::encode_request(&self.name, registration_id)
+  }
+}
+
 impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static + Clone + Send + Sync> MultiMapProxy<K, V> {
   pub fn new(
     base: ProxyBase,
@@ -46,7 +118,7 @@ impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static
         let key_data = key_data.clone();
 
         Box::pin(async move {
-          MultiMapGetCodec::encode_request(&value, &key_data, &0).await
+          MultiMapGetCodec::encode_request(&value, &key_data, &ThreadId::current()).await
         })
       }),
       Box::pin({
@@ -83,7 +155,7 @@ impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static
           let key_data = key_data.clone();
           let value_data = value_data.clone();
           async move {
-            MultiMapRemoveEntryCodec::encode_request(&name, &key_data, &value_data, &0).await
+            MultiMapRemoveEntryCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current()).await
           }
         })
       }),
@@ -93,15 +165,15 @@ impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static
     ).await
   }
 
-  pub async fn put(&self, key: impl Into<K>, value: impl Into<V>) {
+  pub async fn put(&self, key: impl Into<K>, value: impl Into<V>) -> bool {
     let key = key.into();
     let value = value.into();
     let key_data = self.base.to_data(Box::new(key));
     let value_data = self.base.to_data(Box::new(value));
-    self.put_internal(key_data, value_data).await;
+    self.put_internal(key_data, value_data).await
   }
 
-  async fn put_internal(&self, key_data: HeapData, value_data: HeapData) {
+  async fn put_internal(&self, key_data: HeapData, value_data: HeapData) -> bool {
     self.base.encode_invoke_on_key(
       key_data.clone(),
       Box::pin({
@@ -109,12 +181,364 @@ impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static
           let key_data = key_data.clone();
           let value_data = value_data.clone();
           async move {
-            MultiMapPutCodec::encode_request(&name, &key_data, &value_data, &0).await
+            MultiMapPutCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current()).await
           }
         })
       }),
       Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapPutCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Groups the entries by partition and stores each group in one request.
+  pub async fn put_all(&self, entries: impl IntoIterator<Item=(K, Vec<V>)>) {
+    let mut partitions: HashMap<i32, Vec<(HeapData, Vec<HeapData>)>> = HashMap::new();
+    for (key, values) in entries {
+      let key_data = self.base.to_data(Box::new(key));
+      let values_data = values.into_iter().map(|value| self.base.to_data(Box::new(value))).collect();
+      let partition_id = self.base.partition_service.get_partition_id(key_data.clone()).await;
+      partitions.entry(partition_id).or_default().push((key_data, values_data));
+    }
+
+    join_all(partitions.into_iter().map(|(partition_id, entries_data)| {
+      self.put_all_internal(partition_id, entries_data)
+    })).await;
+  }
+
+  async fn put_all_internal(&self, partition_id: i32, entries_data: Vec<(HeapData, Vec<HeapData>)>) {
+    self.base.encode_invoke_on_partition(
+      partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let entries_data = entries_data.clone();
+          async move {
+            MultiMapPutAllCodec::encode_request(&name, &entries_data).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Removes all values of the key and returns them.
+  pub async fn remove_all(&self, key: impl Into<K>) -> Vec<V> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let values_data = self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapRemoveCodec::encode_request(&name, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapRemoveCodec::decode_response(&mut response).await)) })),
+    ).await;
+    let mut values = vec![];
+    for value_data in values_data {
+      values.push(*self.base.serialization_service.to_object::<V>(value_data).await);
+    }
+    values
+  }
+
+  /// Like `remove_all`, but without sending the removed values back.
+  pub async fn delete(&self, key: impl Into<K>) {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapDeleteCodec::encode_request(&name, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn clear(&self) {
+    self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MultiMapClearCodec::encode_request(&name).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Total number of values over all keys.
+  pub async fn size(&self) -> i32 {
+    self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MultiMapSizeCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapSizeCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn value_count(&self, key: impl Into<K>) -> i32 {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapValueCountCodec::encode_request(&name, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapValueCountCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_key(&self, key: impl Into<K>) -> bool {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapContainsKeyCodec::encode_request(&name, &key_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapContainsKeyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_value(&self, value: impl Into<V>) -> bool {
+    let value_data = self.base.to_data(Box::new(value.into()));
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let value_data = value_data.clone();
+          async move {
+            MultiMapContainsValueCodec::encode_request(&name, &value_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapContainsValueCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_entry(&self, key: impl Into<K>, value: impl Into<V>) -> bool {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.base.to_data(Box::new(value.into()));
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            MultiMapContainsEntryCodec::encode_request(&name, &key_data, &value_data, &ThreadId::current()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapContainsEntryCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn key_set(&self) -> ReadOnlyLazyList<K> {
+    let keys_data = self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MultiMapKeySetCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapKeySetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(keys_data, self.base.serialization_service.clone(), |serialization_service, key_data| {
+      Box::pin(async move { *serialization_service.to_object::<K>(key_data).await })
+    })
+  }
+
+  pub async fn values(&self) -> ReadOnlyLazyList<V> {
+    let values_data = self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MultiMapValuesCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapValuesCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(values_data, self.base.serialization_service.clone(), |serialization_service, value_data| {
+      Box::pin(async move { *serialization_service.to_object::<V>(value_data).await })
+    })
+  }
+
+  /// One entry per value, so a key shows up once for each of its values.
+  pub async fn entry_set(&self) -> ReadOnlyLazyList<(K, V), (HeapData, HeapData)> {
+    let entries_data = self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { MultiMapEntrySetCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapEntrySetCodec::decode_response(&mut response).await)) })),
     ).await;
+    ReadOnlyLazyList::new(entries_data, self.base.serialization_service.clone(), |serialization_service, (key_data, value_data)| {
+      Box::pin(async move {
+        let key = serialization_service.to_object::<K>(key_data).await;
+        let value = serialization_service.to_object::<V>(value_data).await;
+        (*key, *value)
+      })
+    })
+  }
+
+  /// Acquires the lock for the current `ThreadId::scope`.
+  pub async fn lock(&self, key: impl Into<K>) -> Result<(), MissingThreadIdScopeError> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.lock_internal(key_data, None, ThreadId::scoped()?).await;
+    Ok(())
+  }
+
+  pub async fn lock_with_lease(&self, key: impl Into<K>, lease: Duration) -> Result<(), MissingThreadIdScopeError> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.lock_internal(key_data, Some(lease), ThreadId::scoped()?).await;
+    Ok(())
+  }
+
+  pub async fn lock_guard(&self, key: impl Into<K>) -> Result<LockGuard, MissingThreadIdScopeError> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let thread_id = ThreadId::scoped()?;
+    self.lock_internal(key_data.clone(), None, thread_id).await;
+    Ok(self.lock_guard_internal(key_data, thread_id))
+  }
+
+  pub async fn try_lock(&self, key: impl Into<K>, timeout: Duration, lease: Option<Duration>) -> Result<bool, MissingThreadIdScopeError> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    Ok(self.try_lock_internal(key_data, timeout, lease, ThreadId::scoped()?).await)
+  }
+
+  pub async fn try_lock_guard(&self, key: impl Into<K>, timeout: Duration, lease: Option<Duration>) -> Result<Option<LockGuard>, MissingThreadIdScopeError> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let thread_id = ThreadId::scoped()?;
+    if self.try_lock_internal(key_data.clone(), timeout, lease, thread_id).await {
+      Ok(Some(self.lock_guard_internal(key_data, thread_id)))
+    } else {
+      Ok(None)
+    }
+  }
+
+  pub async fn unlock(&self, key: impl Into<K>) -> Result<(), MissingThreadIdScopeError> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.unlock_internal(key_data, ThreadId::scoped()?).await;
+    Ok(())
+  }
+
+  pub async fn is_locked(&self, key: impl Into<K>) -> bool {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapIsLockedCodec::encode_request(&name, &key_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapIsLockedCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn force_unlock(&self, key: impl Into<K>) {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let reference_id = self.base.lock_reference_id_generator.get_next_reference_id();
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapForceUnlockCodec::encode_request(&name, &key_data, &reference_id).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  fn lock_guard_internal(&self, key_data: HeapData, thread_id: i64) -> LockGuard {
+    let this = self.clone();
+    LockGuard::new(async move {
+      this.unlock_internal(key_data, thread_id).await;
+    })
+  }
+
+  async fn lock_internal(&self, key_data: HeapData, lease: Option<Duration>, thread_id: i64) {
+    let reference_id = self.base.lock_reference_id_generator.get_next_reference_id();
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapLockCodec::encode_request(&name, &key_data, &thread_id, &lease.map(|lease| lease.as_millis() as i64).unwrap_or(-1), &reference_id).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  async fn try_lock_internal(&self, key_data: HeapData, timeout: Duration, lease: Option<Duration>, thread_id: i64) -> bool {
+    let reference_id = self.base.lock_reference_id_generator.get_next_reference_id();
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapTryLockCodec::encode_request(&name, &key_data, &thread_id, &lease.map(|lease| lease.as_millis() as i64).unwrap_or(-1), &(timeout.as_millis() as i64), &reference_id).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(MultiMapTryLockCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn unlock_internal(&self, key_data: HeapData, thread_id: i64) {
+    let reference_id = self.base.lock_reference_id_generator.get_next_reference_id();
+    self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            MultiMapUnlockCodec::encode_request(&name, &key_data, &thread_id, &reference_id).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Returns the registration id to pass to `remove_entry_listener`. A multimap
+  /// only reports added and removed entries and cleared maps.
+  pub async fn add_entry_listener(&self, listener: impl EntryListener<K, V>, include_value: bool) -> Uuid {
+    self.add_entry_listener_internal(listener, include_value, None).await
+  }
+
+  pub async fn add_entry_listener_to_key(&self, listener: impl EntryListener<K, V>, key: impl Into<K>, include_value: bool) -> Uuid {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.add_entry_listener_internal(listener, include_value, Some(key_data)).await
+  }
+
+  pub async fn remove_entry_listener(&self, registration_id: Uuid) -> bool {
+    self.base.listener_service.deregister_listener(registration_id).await
+  }
+
+  async fn add_entry_listener_internal(&self, listener: impl EntryListener<K, V>, include_value: bool, key_data: Option<HeapData>) -> Uuid {
+    let codec = MultiMapEntryListenerCodec::new(self.base.name.clone(), include_value, key_data);
+    let listener = Arc::new(listener);
+    let base = self.base.clone();
+    self.base.listener_service.register_listener(codec.clone(), {
+      move |mut client_message| {
+        let codec = codec.clone();
+        let listener = listener.clone();
+        let base = base.clone();
+        Box::pin(async move {
+          codec.handle(&mut client_message, Some(Box::pin({
+            move |key, value, old_value, merging_value, event_type, uuid, number_of_affected_entries| {
+              let listener = listener.clone();
+              let base = base.clone();
+              Box::pin(async move {
+                dispatch_entry_event(&base, &*listener, key, value, old_value, merging_value, event_type, uuid, number_of_affected_entries).await;
+              })
+            }
+          }))).await;
+        })
+      }
+    }).await
   }
 }
 
@@ -0,0 +1,15 @@
+/// What a ringbuffer does when adding an item would overwrite one that is
+/// still within its time to live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+  /// Overwrites the oldest item anyway.
+  Overwrite = 0,
+  /// Rejects the item, which `add` reports as sequence `-1`.
+  Fail = 1,
+}
+
+impl OverflowPolicy {
+  pub fn id(&self) -> i32 {
+    *self as i32
+  }
+}
@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::transmute;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use lazy_static::lazy_static;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use crate::codec::queue_add_all_codec::QueueAddAllCodec;
+use crate::codec::queue_add_listener_codec::QueueAddListenerCodec;
+use crate::codec::queue_clear_codec::QueueClearCodec;
+use crate::codec::queue_contains_all_codec::QueueContainsAllCodec;
+use crate::codec::queue_contains_codec::QueueContainsCodec;
+use crate::codec::queue_drain_to_codec::QueueDrainToCodec;
+use crate::codec::queue_drain_to_max_size_codec::QueueDrainToMaxSizeCodec;
+use crate::codec::queue_is_empty_codec::QueueIsEmptyCodec;
+use crate::codec::queue_iterator_codec::QueueIteratorCodec;
+use crate::codec::queue_offer_codec::QueueOfferCodec;
+use crate::codec::queue_peek_codec::QueuePeekCodec;
+use crate::codec::queue_poll_codec::QueuePollCodec;
+use crate::codec::queue_put_codec::QueuePutCodec;
+use crate::codec::queue_remaining_capacity_codec::QueueRemainingCapacityCodec;
+use crate::codec::queue_remove_codec::QueueRemoveCodec;
+use crate::codec::queue_remove_listener_codec::QueueRemoveListenerCodec;
+use crate::codec::queue_size_codec::QueueSizeCodec;
+use crate::codec::queue_take_codec::QueueTakeCodec;
+use crate::core::readonly_lazy_list::ReadOnlyLazyList;
+use crate::listener::message_codec::ListenerMessageCodec;
+use crate::protocol::client_message::ClientMessage;
+use crate::proxy::base::{HasProxyBase, ProxyBase};
+use crate::proxy::item_listener::{dispatch_item_event, ItemListener};
+use crate::proxy::map_proxy::AnySend;
+use crate::proxy::Proxy;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+use crate::util::maybe_future::MaybeFuture;
+
+/// A queue lives on a single partition, so every request goes to the
+/// partition that was resolved from the name when the proxy was created.
+#[derive(Clone)]
+pub struct QueueProxy<T: Serializable> {
+  base: ProxyBase,
+  partition_id: i32,
+  phantom: PhantomData<T>,
+}
+
+#[derive(Clone)]
+struct QueueItemListenerCodec {
+  name: String,
+  include_value: bool,
+}
+
+impl QueueItemListenerCodec {
+  pub fn new(name: String, include_value: bool) -> Self {
+    Self {
+      name,
+      include_value,
+    }
+  }
+}
+
+impl ListenerMessageCodec for QueueItemListenerCodec {
+  fn encode_add_request<'a>(&'a self, local_only: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    QueueAddListenerCodec::encode_request(&self.name, &self.include_value, local_only)
+  }
+
+  fn decode_add_response<'a>(&'a self, client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Uuid> + Send + Sync + 'a>> {
+    QueueAddListenerCodec::decode_response(client_message)
+  }
+
+  fn encode_remove_request<'a>(&'a self, registration_id: &'a Uuid) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    QueueRemoveListenerCodec::encode_request(&self.name, registration_id)
+  }
+}
+
+impl<T: Serializable + Clone + Send + Sync + 'static> QueueProxy<T> {
+  pub fn new(
+    base: ProxyBase,
+    partition_id: i32,
+  ) -> Self {
+    QueueProxy {
+      base,
+      partition_id,
+      phantom: PhantomData::default(),
+    }
+  }
+
+  /// Inserts the item if there is capacity left, without waiting.
+  pub async fn offer(&self, item: impl Into<T>) -> bool {
+    self.offer_with_timeout(item, Duration::ZERO).await
+  }
+
+  /// Waits up to `timeout` for capacity to become available.
+  pub async fn offer_with_timeout(&self, item: impl Into<T>, timeout: Duration) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            QueueOfferCodec::encode_request(&name, &item_data, &(timeout.as_millis() as i64)).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueOfferCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Inserts the item, waiting as long as necessary for capacity.
+  pub async fn put(&self, item: impl Into<T>) {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            QueuePutCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Removes the head of the queue, or returns `None` if it is empty.
+  pub async fn poll(&self) -> Option<T> {
+    self.poll_with_timeout(Duration::ZERO).await
+  }
+
+  pub async fn poll_with_timeout(&self, timeout: Duration) -> Option<T> {
+    let item_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(move |name| Box::pin(async move { QueuePollCodec::encode_request(&name, &(timeout.as_millis() as i64)).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueuePollCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_item(item_data).await
+  }
+
+  /// Removes the head of the queue, waiting until an item is available.
+  /// Returns `None` if the member gives up waiting, e.g. because the queue
+  /// was destroyed.
+  pub async fn take(&self) -> Option<T> {
+    let item_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { QueueTakeCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueTakeCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_item(item_data).await
+  }
+
+  pub async fn peek(&self) -> Option<T> {
+    let item_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { QueuePeekCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueuePeekCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_item(item_data).await
+  }
+
+  pub async fn size(&self) -> i32 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { QueueSizeCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueSizeCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn is_empty(&self) -> bool {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { QueueIsEmptyCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueIsEmptyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// `i32::MAX` for unbounded queues.
+  pub async fn remaining_capacity(&self) -> i32 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { QueueRemainingCapacityCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueRemainingCapacityCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn remove(&self, item: impl Into<T>) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            QueueRemoveCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueRemoveCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains(&self, item: impl Into<T>) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            QueueContainsCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueContainsCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data: Vec<HeapData> = items.into_iter().map(|item| self.base.to_data(Box::new(item))).collect();
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            QueueContainsAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueContainsAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Returns `true` if the queue changed.
+  pub async fn add_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data: Vec<HeapData> = items.into_iter().map(|item| self.base.to_data(Box::new(item))).collect();
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            QueueAddAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueAddAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Removes all items and returns them in queue order.
+  pub async fn drain_to(&self) -> Vec<T> {
+    let items_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { QueueDrainToCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueDrainToCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_items(items_data).await
+  }
+
+  /// Removes at most `max_size` items from the head of the queue.
+  pub async fn drain_to_max_size(&self, max_size: i32) -> Vec<T> {
+    let items_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(move |name| Box::pin(async move { QueueDrainToMaxSizeCodec::encode_request(&name, &max_size).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueDrainToMaxSizeCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_items(items_data).await
+  }
+
+  pub async fn clear(&self) {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { QueueClearCodec::encode_request(&name).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Snapshot of the items in queue order, without removing them.
+  pub async fn iterator(&self) -> ReadOnlyLazyList<T> {
+    let items_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { QueueIteratorCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(QueueIteratorCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(items_data, self.base.serialization_service.clone(), |serialization_service, item_data| {
+      Box::pin(async move { *serialization_service.to_object::<T>(item_data).await })
+    })
+  }
+
+  /// Returns the registration id to pass to `remove_item_listener`.
+  pub async fn add_item_listener(&self, listener: impl ItemListener<T>, include_value: bool) -> Uuid {
+    let codec = QueueItemListenerCodec::new(self.base.name.clone(), include_value);
+    let listener = Arc::new(listener);
+    let base = self.base.clone();
+    self.base.listener_service.register_listener(codec.clone(), {
+      move |mut client_message| {
+        let listener = listener.clone();
+        let base = base.clone();
+        Box::pin(async move {
+          QueueAddListenerCodec::handle(&mut client_message, Some(Box::pin({
+            move |item, uuid, event_type| {
+              let listener = listener.clone();
+              let base = base.clone();
+              Box::pin(async move {
+                dispatch_item_event(&base, &*listener, item, uuid, event_type).await;
+              })
+            }
+          }))).await;
+        })
+      }
+    }).await
+  }
+
+  pub async fn remove_item_listener(&self, registration_id: Uuid) -> bool {
+    self.base.listener_service.deregister_listener(registration_id).await
+  }
+
+  async fn to_item(&self, item_data: Option<HeapData>) -> Option<T> {
+    match item_data {
+      Some(item_data) => Some(*self.base.serialization_service.to_object(item_data).await),
+      None => None,
+    }
+  }
+
+  async fn to_items(&self, items_data: Vec<HeapData>) -> Vec<T> {
+    let mut items = Vec::with_capacity(items_data.len());
+    for item_data in items_data {
+      items.push(*self.base.serialization_service.to_object(item_data).await);
+    }
+    items
+  }
+}
+
+impl<T: Clone + Send + Sync + Serializable + 'static> Proxy for QueueProxy<T> {
+  const SERVICE_NAME: &'static str = "hz:impl:queueService";
+  fn get_proxies() -> Arc<RwLock<HashMap<String, Box<MaybeFuture<Self>>>>> {
+    lazy_static! {
+      static ref PROXIES: Arc<RwLock<HashMap<String, Box<dyn AnySend>>>> = Arc::new(RwLock::new(HashMap::new()));
+    }
+    unsafe { transmute(PROXIES.clone()) }
+  }
+  fn create_proxy(base: ProxyBase) -> Pin<Box<dyn Future<Output=Self> + Send + Sync>> {
+    Box::pin(async move {
+      let partition_id = base.get_partition_id_for_name().await;
+      Self::new(base, partition_id)
+    })
+  }
+}
+
+impl<T: Send + Sync + Serializable + 'static> HasProxyBase for QueueProxy<T> {
+  fn get_proxy_base(&self) -> &ProxyBase {
+    &self.base
+  }
+}
@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::transmute;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use lazy_static::lazy_static;
+use rand::Rng;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use crate::codec::replicated_map_add_entry_listener_codec::ReplicatedMapAddEntryListenerCodec;
+use crate::codec::replicated_map_add_entry_listener_to_key_codec::ReplicatedMapAddEntryListenerToKeyCodec;
+use crate::codec::replicated_map_add_entry_listener_to_key_with_predicate_codec::ReplicatedMapAddEntryListenerToKeyWithPredicateCodec;
+use crate::codec::replicated_map_add_entry_listener_with_predicate_codec::ReplicatedMapAddEntryListenerWithPredicateCodec;
+use crate::codec::replicated_map_clear_codec::ReplicatedMapClearCodec;
+use crate::codec::replicated_map_contains_key_codec::ReplicatedMapContainsKeyCodec;
+use crate::codec::replicated_map_contains_value_codec::ReplicatedMapContainsValueCodec;
+use crate::codec::replicated_map_entry_set_codec::ReplicatedMapEntrySetCodec;
+use crate::codec::replicated_map_get_codec::ReplicatedMapGetCodec;
+use crate::codec::replicated_map_is_empty_codec::ReplicatedMapIsEmptyCodec;
+use crate::codec::replicated_map_key_set_codec::ReplicatedMapKeySetCodec;
+use crate::codec::replicated_map_put_all_codec::ReplicatedMapPutAllCodec;
+use crate::codec::replicated_map_put_codec::ReplicatedMapPutCodec;
+use crate::codec::replicated_map_remove_codec::ReplicatedMapRemoveCodec;
+use crate::codec::replicated_map_remove_entry_listener_codec::ReplicatedMapRemoveEntryListenerCodec;
+use crate::codec::replicated_map_size_codec::ReplicatedMapSizeCodec;
+use crate::codec::replicated_map_values_codec::ReplicatedMapValuesCodec;
+use crate::core::readonly_lazy_list::ReadOnlyLazyList;
+use crate::invocation::InvocationReturnValue;
+use crate::listener::message_codec::ListenerMessageCodec;
+use crate::protocol::client_message::ClientMessage;
+use crate::proxy::base::{HasProxyBase, ProxyBase};
+use crate::proxy::entry_listener::{dispatch_entry_event, EntryEventHandler, EntryListener};
+use crate::proxy::map_proxy::AnySend;
+use crate::proxy::Proxy;
+use crate::serialization::default_predicates::Predicate;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+use crate::util::maybe_future::MaybeFuture;
+
+/// Every member holds a full copy of a replicated map, so reads go to a
+/// partition picked once per proxy, even if they are bound to a key. This
+/// keeps them on the same member and makes them see a consistent view.
+/// Mutations go to the owner of the key's partition.
+#[derive(Clone)]
+pub struct ReplicatedMapProxy<K: Serializable, V: Serializable> {
+  base: ProxyBase,
+  target_partition_id: Arc<RwLock<Option<i32>>>,
+  phantom: PhantomData<(K, V)>,
+}
+
+#[derive(Clone)]
+struct ReplicatedMapEntryListenerCodec {
+  name: String,
+  key: Option<HeapData>,
+  predicate: Option<HeapData>,
+}
+
+impl ReplicatedMapEntryListenerCodec {
+  pub fn new(name: String, key: Option<HeapData>, predicate: Option<HeapData>) -> Self {
+    Self {
+      name,
+      key,
+      predicate,
+    }
+  }
+
+  async fn handle(&self, client_message: &mut ClientMessage, handle_entry_event: Option<EntryEventHandler>) {
+    match (&self.key, &self.predicate) {
+      (None, None) => ReplicatedMapAddEntryListenerCodec::handle(client_message, handle_entry_event).await,
+      (Some(_), None) => ReplicatedMapAddEntryListenerToKeyCodec::handle(client_message, handle_entry_event).await,
+      (None, Some(_)) => ReplicatedMapAddEntryListenerWithPredicateCodec::handle(client_message, handle_entry_event).await,
+      (Some(_), Some(_)) => ReplicatedMapAddEntryListenerToKeyWithPredicateCodec::handle(client_message, handle_entry_event).await,
+    }
+  }
+}
+
+impl ListenerMessageCodec for ReplicatedMapEntryListenerCodec {
+  fn encode_add_request<'a>(&'a self, local_only: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    match (&self.key, &self.predicate) {
+      (None, None) => ReplicatedMapAddEntryListenerCodec::encode_request(&self.name, local_only),
+      (Some(key), None) => ReplicatedMapAddEntryListenerToKeyCodec::encode_request(&self.name, key, local_only),
+      (None, Some(predicate)) => ReplicatedMapAddEntryListenerWithPredicateCodec::encode_request(&self.name, predicate, local_only),
+      (Some(key), Some(predicate)) => ReplicatedMapAddEntryListenerToKeyWithPredicateCodec::encode_request(&self.name, key, predicate, local_only),
+    }
+  }
+
+  fn decode_add_response<'a>(&'a self, client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Uuid> + Send + Sync + 'a>> {
+    match (&self.key, &self.predicate) {
+      (None, None) => ReplicatedMapAddEntryListenerCodec::decode_response(client_message),
+      (Some(_), None) => ReplicatedMapAddEntryListenerToKeyCodec::decode_response(client_message),
+      (None, Some(_)) => ReplicatedMapAddEntryListenerWithPredicateCodec::decode_response(client_message),
+      (Some(_), Some(_)) => ReplicatedMapAddEntryListenerToKeyWithPredicateCodec::decode_response(client_message),
+    }
+  }
+
+  fn encode_remove_request<'a>(&'a self, registration_id: &'a Uuid) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    ReplicatedMapRemoveEntryListenerCodec::encode_request(&self.name, registration_id)
+  }
+}
+
+impl<K: Serializable + Send + Sync + Clone + 'static, V: Serializable + 'static + Clone + Send + Sync> ReplicatedMapProxy<K, V> {
+  pub fn new(base: ProxyBase) -> Self {
+    ReplicatedMapProxy {
+      base,
+      target_partition_id: Arc::new(RwLock::new(None)),
+      phantom: PhantomData::default(),
+    }
+  }
+
+  pub async fn put(&self, key: impl Into<K>, value: impl Into<V>) -> Option<V> {
+    self.put_internal(key.into(), value.into(), 0).await
+  }
+
+  /// A `ttl` of zero keeps the entry forever.
+  pub async fn put_with_ttl(&self, key: impl Into<K>, value: impl Into<V>, ttl: Duration) -> Option<V> {
+    self.put_internal(key.into(), value.into(), ttl.as_millis() as i64).await
+  }
+
+  /// Sends all entries in one request, the members replicate them from there.
+  pub async fn put_all(&self, entries: impl IntoIterator<Item=(K, V)>) {
+    let entries_data: Vec<(HeapData, HeapData)> = entries.into_iter()
+      .map(|(key, value)| (self.base.to_data(Box::new(key)), self.base.to_data(Box::new(value))))
+      .collect();
+    self.base.encode_invoke(
+      Box::pin({
+        move |name| Box::pin({
+          let entries_data = entries_data.clone();
+          async move {
+            ReplicatedMapPutAllCodec::encode_request(&name, &entries_data).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn get(&self, key: impl Into<K>) -> Option<V> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.invoke_on_target_partition(
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            ReplicatedMapGetCodec::encode_request(&name, &key_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapGetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_value(value_data).await
+  }
+
+  pub async fn remove(&self, key: impl Into<K>) -> Option<V> {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let value_data = self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            ReplicatedMapRemoveCodec::encode_request(&name, &key_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapRemoveCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_value(value_data).await
+  }
+
+  pub async fn contains_key(&self, key: impl Into<K>) -> bool {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.invoke_on_target_partition(
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          async move {
+            ReplicatedMapContainsKeyCodec::encode_request(&name, &key_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapContainsKeyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_value(&self, value: impl Into<V>) -> bool {
+    let value_data = self.base.to_data(Box::new(value.into()));
+    self.invoke_on_target_partition(
+      Box::pin({
+        move |name| Box::pin({
+          let value_data = value_data.clone();
+          async move {
+            ReplicatedMapContainsValueCodec::encode_request(&name, &value_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapContainsValueCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn size(&self) -> i32 {
+    self.invoke_on_target_partition(
+      Box::pin(|name| Box::pin(async move { ReplicatedMapSizeCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapSizeCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn is_empty(&self) -> bool {
+    self.invoke_on_target_partition(
+      Box::pin(|name| Box::pin(async move { ReplicatedMapIsEmptyCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapIsEmptyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn clear(&self) {
+    self.base.encode_invoke(
+      Box::pin(|name| Box::pin(async move { ReplicatedMapClearCodec::encode_request(&name).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn key_set(&self) -> ReadOnlyLazyList<K> {
+    let keys_data = self.invoke_on_target_partition(
+      Box::pin(|name| Box::pin(async move { ReplicatedMapKeySetCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapKeySetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(keys_data, self.base.serialization_service.clone(), |serialization_service, key_data| {
+      Box::pin(async move { *serialization_service.to_object::<K>(key_data).await })
+    })
+  }
+
+  pub async fn values(&self) -> ReadOnlyLazyList<V> {
+    let values_data = self.invoke_on_target_partition(
+      Box::pin(|name| Box::pin(async move { ReplicatedMapValuesCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapValuesCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(values_data, self.base.serialization_service.clone(), |serialization_service, value_data| {
+      Box::pin(async move { *serialization_service.to_object::<V>(value_data).await })
+    })
+  }
+
+  pub async fn entry_set(&self) -> ReadOnlyLazyList<(K, V), (HeapData, HeapData)> {
+    let entries_data = self.invoke_on_target_partition(
+      Box::pin(|name| Box::pin(async move { ReplicatedMapEntrySetCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapEntrySetCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(entries_data, self.base.serialization_service.clone(), |serialization_service, (key_data, value_data)| {
+      Box::pin(async move {
+        let key = serialization_service.to_object::<K>(key_data).await;
+        let value = serialization_service.to_object::<V>(value_data).await;
+        (*key, *value)
+      })
+    })
+  }
+
+  /// Returns the registration id to pass to `remove_entry_listener`.
+  pub async fn add_entry_listener(&self, listener: impl EntryListener<K, V>) -> Uuid {
+    self.add_entry_listener_internal(listener, None, None).await
+  }
+
+  pub async fn add_entry_listener_to_key(&self, listener: impl EntryListener<K, V>, key: impl Into<K>) -> Uuid {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    self.add_entry_listener_internal(listener, Some(key_data), None).await
+  }
+
+  pub async fn add_entry_listener_with_predicate(&self, listener: impl EntryListener<K, V>, predicate: Predicate) -> Uuid {
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    self.add_entry_listener_internal(listener, None, Some(predicate_data)).await
+  }
+
+  pub async fn add_entry_listener_to_key_with_predicate(&self, listener: impl EntryListener<K, V>, key: impl Into<K>, predicate: Predicate) -> Uuid {
+    let key_data = self.base.to_data(Box::new(key.into()));
+    let predicate_data = self.base.to_data(Box::new(predicate));
+    self.add_entry_listener_internal(listener, Some(key_data), Some(predicate_data)).await
+  }
+
+  pub async fn remove_entry_listener(&self, registration_id: Uuid) -> bool {
+    self.base.listener_service.deregister_listener(registration_id).await
+  }
+
+  async fn add_entry_listener_internal(&self, listener: impl EntryListener<K, V>, key_data: Option<HeapData>, predicate_data: Option<HeapData>) -> Uuid {
+    let codec = ReplicatedMapEntryListenerCodec::new(self.base.name.clone(), key_data, predicate_data);
+    let listener = Arc::new(listener);
+    let base = self.base.clone();
+    self.base.listener_service.register_listener(codec.clone(), {
+      move |mut client_message| {
+        let codec = codec.clone();
+        let listener = listener.clone();
+        let base = base.clone();
+        Box::pin(async move {
+          codec.handle(&mut client_message, Some(Box::pin({
+            move |key, value, old_value, merging_value, event_type, uuid, number_of_affected_entries| {
+              let listener = listener.clone();
+              let base = base.clone();
+              Box::pin(async move {
+                dispatch_entry_event(&base, &*listener, key, value, old_value, merging_value, event_type, uuid, number_of_affected_entries).await;
+              })
+            }
+          }))).await;
+        })
+      }
+    }).await
+  }
+
+  async fn put_internal(&self, key: K, value: V, ttl: i64) -> Option<V> {
+    let key_data = self.base.to_data(Box::new(key));
+    let value_data = self.base.to_data(Box::new(value));
+    let previous = self.base.encode_invoke_on_key(
+      key_data.clone(),
+      Box::pin({
+        move |name| Box::pin({
+          let key_data = key_data.clone();
+          let value_data = value_data.clone();
+          async move {
+            ReplicatedMapPutCodec::encode_request(&name, &key_data, &value_data, &ttl).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(ReplicatedMapPutCodec::decode_response(&mut response).await)) })),
+    ).await;
+    self.to_value(previous).await
+  }
+
+  async fn to_value(&self, data: Option<HeapData>) -> Option<V> {
+    if let Some(data) = data {
+      Some(*self.base.serialization_service.to_object::<V>(data).await)
+    } else {
+      None
+    }
+  }
+
+  /// Falls back to any member as long as the partition table is not loaded,
+  /// the partition is picked on the first read after that.
+  async fn invoke_on_target_partition<R: InvocationReturnValue + Send + Sync + Clone>(&self, encoder: Pin<Box<dyn Send + Sync + Fn(String) -> Pin<Box<dyn Send + Sync + Future<Output=ClientMessage>>>>>, decoder: Pin<Box<dyn Send + Sync + Fn(ClientMessage) -> Pin<Box<dyn Send + Sync + Future<Output=Box<Box<R>>>>>>>) -> R {
+    match self.get_target_partition_id().await {
+      Some(partition_id) => self.base.encode_invoke_on_partition(partition_id, encoder, decoder).await,
+      None => self.base.encode_invoke(encoder, decoder).await,
+    }
+  }
+
+  async fn get_target_partition_id(&self) -> Option<i32> {
+    if let Some(partition_id) = *self.target_partition_id.read().await {
+      return Some(partition_id);
+    }
+    let partition_count = *self.base.partition_service.partition_count.read().await;
+    if partition_count == 0 {
+      return None;
+    }
+    let mut target_partition_id = self.target_partition_id.write().await;
+    Some(*target_partition_id.get_or_insert_with(|| rand::thread_rng().gen_range(0..partition_count)))
+  }
+}
+
+impl<K: Clone + Send + Sync + Serializable + 'static, V: Clone + Send + Sync + Serializable + 'static> Proxy for ReplicatedMapProxy<K, V> {
+  const SERVICE_NAME: &'static str = "hz:impl:replicatedMapService";
+  fn get_proxies() -> Arc<RwLock<HashMap<String, Box<MaybeFuture<Self>>>>> {
+    lazy_static! {
+      static ref PROXIES: Arc<RwLock<HashMap<String, Box<dyn AnySend>>>> = Arc::new(RwLock::new(HashMap::new()));
+    }
+    unsafe { transmute(PROXIES.clone()) }
+  }
+  fn create_proxy(base: ProxyBase) -> Pin<Box<dyn Future<Output=Self> + Send + Sync>> {
+    Box::pin(async move { Self::new(base) })
+  }
+}
+
+impl<K: Send + Sync + Serializable + 'static, V: Send + Sync + Serializable + 'static> HasProxyBase for ReplicatedMapProxy<K, V> {
+  fn get_proxy_base(&self) -> &ProxyBase {
+    &self.base
+  }
+}
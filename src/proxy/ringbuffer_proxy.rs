@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::transmute;
+use std::pin::Pin;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+use tokio::sync::RwLock;
+use crate::codec::ringbuffer_add_all_codec::RingbufferAddAllCodec;
+use crate::codec::ringbuffer_add_codec::RingbufferAddCodec;
+use crate::codec::ringbuffer_capacity_codec::RingbufferCapacityCodec;
+use crate::codec::ringbuffer_head_sequence_codec::RingbufferHeadSequenceCodec;
+use crate::codec::ringbuffer_read_many_codec::RingbufferReadManyCodec;
+use crate::codec::ringbuffer_read_one_codec::RingbufferReadOneCodec;
+use crate::codec::ringbuffer_remaining_capacity_codec::RingbufferRemainingCapacityCodec;
+use crate::codec::ringbuffer_size_codec::RingbufferSizeCodec;
+use crate::codec::ringbuffer_tail_sequence_codec::RingbufferTailSequenceCodec;
+use crate::core::readonly_lazy_list::ReadOnlyLazyList;
+use crate::proxy::base::{HasProxyBase, ProxyBase};
+use crate::proxy::map_proxy::AnySend;
+use crate::proxy::overflow_policy::OverflowPolicy;
+use crate::proxy::Proxy;
+use crate::proxy::server_side_function::ServerSideFunction;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+use crate::util::maybe_future::MaybeFuture;
+
+/// Most items the members hand out per `read_many`.
+pub const MAX_BATCH_SIZE: i32 = 1000;
+
+/// Returned by `read_many` for counts the members would reject.
+#[derive(Debug, Clone)]
+pub struct InvalidReadCountError {
+  pub min_count: i32,
+  pub max_count: i32,
+}
+
+impl Display for InvalidReadCountError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "cannot read between {} and {} items, counts have to satisfy 0 <= min <= max <= {}", self.min_count, self.max_count, MAX_BATCH_SIZE)
+  }
+}
+
+impl std::error::Error for InvalidReadCountError {}
+
+/// Items returned by `read_many`, together with their sequences.
+pub struct ReadResultSet<T> {
+  /// Number of items that were read, including the ones the filter dropped.
+  pub read_count: i32,
+  pub items: ReadOnlyLazyList<T>,
+  item_seqs: Option<Vec<i64>>,
+  start_sequence: i64,
+  filtered: bool,
+  /// Sequence to pass to the next `read_many` to continue after this batch.
+  pub next_sequence_to_read_from: i64,
+}
+
+impl<T> ReadResultSet<T> {
+  /// Sequence of the item at `index` in `items`. Unknown if a filter was
+  /// used and the member did not send the sequences.
+  pub fn get_sequence(&self, index: usize) -> Option<i64> {
+    match &self.item_seqs {
+      Some(item_seqs) => item_seqs.get(index).cloned(),
+      None if self.filtered => None,
+      None => Some(self.start_sequence + index as i64),
+    }
+  }
+}
+
+/// A ringbuffer lives on the single partition resolved from its name.
+#[derive(Clone)]
+pub struct RingbufferProxy<T: Serializable> {
+  base: ProxyBase,
+  partition_id: i32,
+  phantom: PhantomData<T>,
+}
+
+impl<T: Serializable + Clone + Send + Sync + 'static> RingbufferProxy<T> {
+  pub fn new(
+    base: ProxyBase,
+    partition_id: i32,
+  ) -> Self {
+    RingbufferProxy {
+      base,
+      partition_id,
+      phantom: PhantomData::default(),
+    }
+  }
+
+  pub async fn capacity(&self) -> i64 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { RingbufferCapacityCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferCapacityCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn size(&self) -> i64 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { RingbufferSizeCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferSizeCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Sequence of the newest item, or `-1` if nothing was added yet.
+  pub async fn tail_sequence(&self) -> i64 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { RingbufferTailSequenceCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferTailSequenceCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Sequence of the oldest item that can still be read.
+  pub async fn head_sequence(&self) -> i64 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { RingbufferHeadSequenceCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferHeadSequenceCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Number of items that can be added before items within their time to
+  /// live get overwritten. Equals `capacity` if no time to live is configured.
+  pub async fn remaining_capacity(&self) -> i64 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { RingbufferRemainingCapacityCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferRemainingCapacityCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Returns the sequence of the added item, or `-1` if it was rejected by
+  /// `OverflowPolicy::Fail`.
+  pub async fn add(&self, item: impl Into<T>, overflow_policy: OverflowPolicy) -> i64 {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            RingbufferAddCodec::encode_request(&name, &overflow_policy.id(), &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferAddCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Adds the items in iteration order and returns the sequence of the last
+  /// one, or `-1` if they were rejected by `OverflowPolicy::Fail`.
+  pub async fn add_all(&self, items: impl IntoIterator<Item=T>, overflow_policy: OverflowPolicy) -> i64 {
+    let items_data: Vec<HeapData> = items.into_iter().map(|item| self.base.to_data(Box::new(item))).collect();
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            RingbufferAddAllCodec::encode_request(&name, &items_data, &overflow_policy.id()).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferAddAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Waits for the item at `sequence` if it was not added yet.
+  pub async fn read_one(&self, sequence: i64) -> Option<T> {
+    let item_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(move |name| Box::pin(async move { RingbufferReadOneCodec::encode_request(&name, &sequence).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferReadOneCodec::decode_response(&mut response).await)) })),
+    ).await;
+    match item_data {
+      Some(item_data) => Some(*self.base.serialization_service.to_object(item_data).await),
+      None => None,
+    }
+  }
+
+  /// Reads up to `max_count` items starting at `start_sequence`, waiting
+  /// until at least `min_count` are available. `filter` decides on the member
+  /// which items are returned.
+  pub async fn read_many(&self, start_sequence: i64, min_count: i32, max_count: i32, filter: Option<ServerSideFunction>) -> Result<ReadResultSet<T>, InvalidReadCountError> {
+    if min_count < 0 || max_count < min_count || max_count > MAX_BATCH_SIZE {
+      return Err(InvalidReadCountError { min_count, max_count });
+    }
+    let filtered = filter.is_some();
+    let filter_data = filter.map(|filter| filter.to_data(&self.base.serialization_service));
+    let response = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let filter_data = filter_data.clone();
+          async move {
+            RingbufferReadManyCodec::encode_request(&name, &start_sequence, &min_count, &max_count, &filter_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferReadManyCodec::decode_response(&mut response).await)) })),
+    ).await;
+    Ok(ReadResultSet {
+      read_count: response.read_count,
+      items: ReadOnlyLazyList::new(response.items, self.base.serialization_service.clone(), |serialization_service, item_data| {
+        Box::pin(async move { *serialization_service.to_object::<T>(item_data).await })
+      }),
+      item_seqs: response.item_seqs,
+      start_sequence,
+      filtered,
+      next_sequence_to_read_from: response.next_seq,
+    })
+  }
+}
+
+impl<T: Clone + Send + Sync + Serializable + 'static> Proxy for RingbufferProxy<T> {
+  const SERVICE_NAME: &'static str = "hz:impl:ringbufferService";
+  fn get_proxies() -> Arc<RwLock<HashMap<String, Box<MaybeFuture<Self>>>>> {
+    lazy_static! {
+      static ref PROXIES: Arc<RwLock<HashMap<String, Box<dyn AnySend>>>> = Arc::new(RwLock::new(HashMap::new()));
+    }
+    unsafe { transmute(PROXIES.clone()) }
+  }
+  fn create_proxy(base: ProxyBase) -> Pin<Box<dyn Future<Output=Self> + Send + Sync>> {
+    Box::pin(async move {
+      let partition_id = base.get_partition_id_for_name().await;
+      Self::new(base, partition_id)
+    })
+  }
+}
+
+impl<T: Send + Sync + Serializable + 'static> HasProxyBase for RingbufferProxy<T> {
+  fn get_proxy_base(&self) -> &ProxyBase {
+    &self.base
+  }
+}
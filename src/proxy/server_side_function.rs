@@ -0,0 +1,31 @@
+use std::sync::Arc;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+use crate::serialization::service::SerializationServiceV1;
+
+type FunctionSerializer = Arc<dyn Fn(&Arc<SerializationServiceV1>) -> HeapData + Send + Sync>;
+
+/// A predicate, projection, comparator or filter that runs on the members,
+/// e.g. on the event journal. The client cannot ship code, so the wrapped value has to be
+/// serialized in a way the members understand, i.e. as an
+/// IdentifiedDataSerializable or Compact value whose class is deployed on the
+/// cluster. The same holds for everything else the members execute, like
+/// entry processors.
+#[derive(Clone)]
+pub struct ServerSideFunction {
+  serializer: FunctionSerializer,
+}
+
+impl ServerSideFunction {
+  pub fn new<F: Serializable + Clone + Send + Sync + 'static>(function: F) -> Self {
+    Self {
+      serializer: Arc::new(move |serialization_service| {
+        serialization_service.to_data(Box::new(function.clone()))
+      }),
+    }
+  }
+
+  pub(crate) fn to_data(&self, serialization_service: &Arc<SerializationServiceV1>) -> HeapData {
+    (self.serializer)(serialization_service)
+  }
+}
@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::transmute;
+use std::pin::Pin;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use crate::codec::set_add_all_codec::SetAddAllCodec;
+use crate::codec::set_add_codec::SetAddCodec;
+use crate::codec::set_add_listener_codec::SetAddListenerCodec;
+use crate::codec::set_clear_codec::SetClearCodec;
+use crate::codec::set_compare_and_remove_all_codec::SetCompareAndRemoveAllCodec;
+use crate::codec::set_compare_and_retain_all_codec::SetCompareAndRetainAllCodec;
+use crate::codec::set_contains_all_codec::SetContainsAllCodec;
+use crate::codec::set_contains_codec::SetContainsCodec;
+use crate::codec::set_get_all_codec::SetGetAllCodec;
+use crate::codec::set_is_empty_codec::SetIsEmptyCodec;
+use crate::codec::set_remove_codec::SetRemoveCodec;
+use crate::codec::set_remove_listener_codec::SetRemoveListenerCodec;
+use crate::codec::set_size_codec::SetSizeCodec;
+use crate::core::readonly_lazy_list::ReadOnlyLazyList;
+use crate::listener::message_codec::ListenerMessageCodec;
+use crate::protocol::client_message::ClientMessage;
+use crate::proxy::base::{HasProxyBase, ProxyBase};
+use crate::proxy::item_listener::{dispatch_item_event, ItemListener};
+use crate::proxy::map_proxy::AnySend;
+use crate::proxy::Proxy;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+use crate::util::maybe_future::MaybeFuture;
+
+/// Like queues, a set lives on the single partition resolved from its name.
+#[derive(Clone)]
+pub struct SetProxy<T: Serializable> {
+  base: ProxyBase,
+  partition_id: i32,
+  phantom: PhantomData<T>,
+}
+
+#[derive(Clone)]
+struct SetItemListenerCodec {
+  name: String,
+  include_value: bool,
+}
+
+impl SetItemListenerCodec {
+  pub fn new(name: String, include_value: bool) -> Self {
+    Self {
+      name,
+      include_value,
+    }
+  }
+}
+
+impl ListenerMessageCodec for SetItemListenerCodec {
+  fn encode_add_request<'a>(&'a self, local_only: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    SetAddListenerCodec::encode_request(&self.name, &self.include_value, local_only)
+  }
+
+  fn decode_add_response<'a>(&'a self, client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Uuid> + Send + Sync + 'a>> {
+    SetAddListenerCodec::decode_response(client_message)
+  }
+
+  fn encode_remove_request<'a>(&'a self, registration_id: &'a Uuid) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    SetRemoveListenerCodec::encode_request(&self.name, registration_id)
+  }
+}
+
+impl<T: Serializable + Clone + Send + Sync + 'static> SetProxy<T> {
+  pub fn new(
+    base: ProxyBase,
+    partition_id: i32,
+  ) -> Self {
+    SetProxy {
+      base,
+      partition_id,
+      phantom: PhantomData::default(),
+    }
+  }
+
+  /// Returns `false` if the set already contained the item.
+  pub async fn add(&self, item: impl Into<T>) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            SetAddCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetAddCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn remove(&self, item: impl Into<T>) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            SetRemoveCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetRemoveCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains(&self, item: impl Into<T>) -> bool {
+    let item_data = self.base.to_data(Box::new(item.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let item_data = item_data.clone();
+          async move {
+            SetContainsCodec::encode_request(&name, &item_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetContainsCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn contains_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            SetContainsAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetContainsAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Returns `true` if the set changed.
+  pub async fn add_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            SetAddAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetAddAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn remove_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            SetCompareAndRemoveAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetCompareAndRemoveAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  /// Removes every item that is not contained in `items`.
+  pub async fn retain_all(&self, items: impl IntoIterator<Item=T>) -> bool {
+    let items_data = self.to_items_data(items);
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let items_data = items_data.clone();
+          async move {
+            SetCompareAndRetainAllCodec::encode_request(&name, &items_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetCompareAndRetainAllCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn size(&self) -> i32 {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { SetSizeCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetSizeCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn is_empty(&self) -> bool {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { SetIsEmptyCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetIsEmptyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  pub async fn clear(&self) {
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { SetClearCodec::encode_request(&name).await })),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  pub async fn get_all(&self) -> ReadOnlyLazyList<T> {
+    let items_data = self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin(|name| Box::pin(async move { SetGetAllCodec::encode_request(&name).await })),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(SetGetAllCodec::decode_response(&mut response).await)) })),
+    ).await;
+    ReadOnlyLazyList::new(items_data, self.base.serialization_service.clone(), |serialization_service, item_data| {
+      Box::pin(async move { *serialization_service.to_object::<T>(item_data).await })
+    })
+  }
+
+  /// Returns the registration id to pass to `remove_item_listener`.
+  pub async fn add_item_listener(&self, listener: impl ItemListener<T>, include_value: bool) -> Uuid {
+    let codec = SetItemListenerCodec::new(self.base.name.clone(), include_value);
+    let listener = Arc::new(listener);
+    let base = self.base.clone();
+    self.base.listener_service.register_listener(codec.clone(), {
+      move |mut client_message| {
+        let listener = listener.clone();
+        let base = base.clone();
+        Box::pin(async move {
+          SetAddListenerCodec::handle(&mut client_message, Some(Box::pin({
+            move |item, uuid, event_type| {
+              let listener = listener.clone();
+              let base = base.clone();
+              Box::pin(async move {
+                dispatch_item_event(&base, &*listener, item, uuid, event_type).await;
+              })
+            }
+          }))).await;
+        })
+      }
+    }).await
+  }
+
+  pub async fn remove_item_listener(&self, registration_id: Uuid) -> bool {
+    self.base.listener_service.deregister_listener(registration_id).await
+  }
+
+  fn to_items_data(&self, items: impl IntoIterator<Item=T>) -> Vec<HeapData> {
+    items.into_iter().map(|item| self.base.to_data(Box::new(item))).collect()
+  }
+}
+
+impl<T: Clone + Send + Sync + Serializable + 'static> Proxy for SetProxy<T> {
+  const SERVICE_NAME: &'static str = "hz:impl:setService";
+  fn get_proxies() -> Arc<RwLock<HashMap<String, Box<MaybeFuture<Self>>>>> {
+    lazy_static! {
+      static ref PROXIES: Arc<RwLock<HashMap<String, Box<dyn AnySend>>>> = Arc::new(RwLock::new(HashMap::new()));
+    }
+    unsafe { transmute(PROXIES.clone()) }
+  }
+  fn create_proxy(base: ProxyBase) -> Pin<Box<dyn Future<Output=Self> + Send + Sync>> {
+    Box::pin(async move {
+      let partition_id = base.get_partition_id_for_name().await;
+      Self::new(base, partition_id)
+    })
+  }
+}
+
+impl<T: Send + Sync + Serializable + 'static> HasProxyBase for SetProxy<T> {
+  fn get_proxy_base(&self) -> &ProxyBase {
+    &self.base
+  }
+}
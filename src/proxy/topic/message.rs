@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use crate::core::member::Member;
+
+/// A message received from a topic.
+pub struct Message<T> {
+  pub name: String,
+  pub message_object: T,
+  /// Milliseconds since the epoch, as set by the publishing member.
+  pub publish_time: i64,
+  /// `None` if the member already left the cluster when the message arrived.
+  pub publishing_member: Option<Arc<Member>>,
+}
+
+impl<T> Message<T> {
+  pub fn new(name: String, message_object: T, publish_time: i64, publishing_member: Option<Arc<Member>>) -> Self {
+    Self {
+      name,
+      message_object,
+      publish_time,
+      publishing_member,
+    }
+  }
+}
@@ -0,0 +1,7 @@
+use async_trait_with_sync::async_trait;
+use crate::proxy::topic::message::Message;
+
+#[async_trait]
+pub trait MessageListener<T: Send + Sync + 'static>: Send + Sync + 'static {
+  async fn on_message(&self, message: Message<T>);
+}
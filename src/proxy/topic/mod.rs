@@ -1 +1,6 @@
-pub mod reliable_topic_message;
\ No newline at end of file
+pub mod message;
+pub mod message_listener;
+pub mod reliable_message_listener;
+pub mod reliable_topic_message;
+pub mod reliable_topic_proxy;
+pub mod topic_proxy;
@@ -0,0 +1,43 @@
+use std::error::Error;
+use async_trait_with_sync::async_trait;
+use crate::proxy::topic::message::Message;
+
+/// Where a reliable topic listener starts reading the ringbuffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartSequence {
+  /// The oldest message that is still in the ringbuffer.
+  Oldest,
+  /// The first message published after the listener was added.
+  Latest,
+  /// A sequence kept from an earlier run, see `store_sequence`.
+  Stored(i64),
+}
+
+/// A message listener that controls how it reads the ringbuffer behind a
+/// reliable topic. Plain `MessageListener`s start with the latest message,
+/// are not loss tolerant and never fail.
+#[async_trait]
+pub trait ReliableMessageListener<T: Send + Sync + 'static>: Send + Sync + 'static {
+  async fn on_message(&self, message: Message<T>) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+  fn start_sequence(&self) -> StartSequence {
+    StartSequence::Latest
+  }
+
+  /// Called with the sequence of every message before it is delivered. To
+  /// resume after that message later, start from `Stored(sequence + 1)`.
+  fn store_sequence(&self, _sequence: i64) {}
+
+  /// A loss tolerant listener skips ahead to the oldest remaining message if
+  /// the ones it should read next were already overwritten. Any other
+  /// listener is removed instead.
+  fn is_loss_tolerant(&self) -> bool {
+    false
+  }
+
+  /// Decides whether the listener is removed after `on_message` failed
+  /// with `error`.
+  fn is_terminal(&self, _error: &(dyn Error + Send + Sync)) -> bool {
+    true
+  }
+}
@@ -5,7 +5,10 @@ use crate::serialization::data::{DataInput, DataInputReadObject, DataOutput, Dat
 use crate::serialization::data::object_data_input::ObjectDataInput;
 use crate::serialization::data::object_data_output::ObjectDataOutput;
 use crate::serialization::heap_data::HeapData;
-use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableSerialization, IdentifiedDataSerializableInfo};
+use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableSerialization, IdentifiedDataSerializableInfo, IdentifiedWrappedSerializer, Serializable};
+use crate::serialization::serializer::default::null_serializer::NullSerializer;
+use crate::serialization::serializer::Serializer;
+use crate::serialization::service::SerializationServiceV1;
 
 
 pub const RELIABLE_TOPIC_MESSAGE_FACTORY_ID: i32 = -9;
@@ -44,17 +47,33 @@ impl IdentifiedDataSerializableInfo for ReliableTopicMessage {
 impl IdentifiedDataSerializableSerialization for ReliableTopicMessage {
   fn read_data(&mut self, input: &mut ObjectDataInput) {
     self.publish_time = Some(input.read_long());
-    self.publisher_address = Some(input.read_object().downcast().unwrap());
+    // Messages published by clients carry no address.
+    let position = input.position();
+    if input.read_int() == NullSerializer::NULL_TYPE_ID {
+      self.publisher_address = None;
+    } else {
+      input.set_position(position);
+      self.publisher_address = input.read_object().downcast().ok();
+    }
     self.payload = input.read_data();
   }
 
   fn write_data(&mut self, output: &mut ObjectDataOutput) {
     output.write_long(self.publish_time.unwrap());
-    output.write_object(self.publisher_address.as_mut().unwrap().as_mut());
+    match self.publisher_address.as_mut() {
+      Some(publisher_address) => output.write_object(publisher_address.as_mut()),
+      None => output.write_int(NullSerializer::NULL_TYPE_ID),
+    }
     output.write_data(self.payload.as_ref());
   }
 }
 
+impl Serializable for ReliableTopicMessage {
+  fn get_serializer(&self, service: &SerializationServiceV1) -> Arc<dyn Serializer<Box<Self>>> {
+    Arc::new(IdentifiedWrappedSerializer::new(service.identified_serializer.clone()))
+  }
+}
+
 pub fn reliable_topic_message_factory(class_id: i32) -> Box<dyn IdentifiedDataSerializable> {
   if class_id == RELIABLE_TOPIC_CLASS_ID {
     Box::new(ReliableTopicMessage::new())
@@ -0,0 +1,289 @@
+use std::cmp::min;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::transmute;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use lazy_static::lazy_static;
+use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use crate::codec::ringbuffer_add_codec::RingbufferAddCodec;
+use crate::codec::ringbuffer_head_sequence_codec::RingbufferHeadSequenceCodec;
+use crate::codec::ringbuffer_read_many_codec::{RingbufferReadManyCodec, RingbufferReadManyResponseParams};
+use crate::codec::ringbuffer_tail_sequence_codec::RingbufferTailSequenceCodec;
+use crate::config::reliable_topic::{ReliableTopicConfig, TopicOverloadPolicy};
+use crate::invocation::InvocationError;
+use crate::proxy::base::{HasProxyBase, ProxyBase};
+use crate::proxy::map_proxy::AnySend;
+use crate::proxy::overflow_policy::OverflowPolicy;
+use crate::proxy::Proxy;
+use crate::proxy::topic::message::Message;
+use crate::proxy::topic::message_listener::MessageListener;
+use crate::proxy::topic::reliable_message_listener::{ReliableMessageListener, StartSequence};
+use crate::proxy::topic::reliable_topic_message::ReliableTopicMessage;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+use crate::util::maybe_future::MaybeFuture;
+
+pub const RINGBUFFER_PREFIX: &str = "_hz_rb_";
+
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_BACKOFF: Duration = Duration::from_millis(2000);
+const STALE_SEQUENCE_EXCEPTION: &str = "com.hazelcast.ringbuffer.StaleSequenceException";
+
+/// Returned by `ReliableTopicProxy::publish` for topics configured with
+/// `TopicOverloadPolicy::Error` when the ringbuffer has no room left.
+#[derive(Debug, Clone)]
+pub struct TopicOverloadError {
+  pub name: String,
+}
+
+impl Display for TopicOverloadError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "failed to publish message on topic {}, the ringbuffer is full", self.name)
+  }
+}
+
+impl std::error::Error for TopicOverloadError {}
+
+/// A topic that stores its messages in the ringbuffer `_hz_rb_<name>`.
+/// Listeners read the ringbuffer in batches from their own sequence, so a slow
+/// listener does not lose messages unless they get overwritten.
+#[derive(Clone)]
+pub struct ReliableTopicProxy<T: Serializable> {
+  base: ProxyBase,
+  ringbuffer_name: String,
+  partition_id: i32,
+  config: ReliableTopicConfig,
+  runners: Arc<Mutex<HashMap<Uuid, JoinHandle<()>>>>,
+  phantom: PhantomData<T>,
+}
+
+struct DefaultReliableMessageListener<L> {
+  listener: L,
+}
+
+#[async_trait_with_sync::async_trait]
+impl<T: Send + Sync + 'static, L: MessageListener<T>> ReliableMessageListener<T> for DefaultReliableMessageListener<L> {
+  async fn on_message(&self, message: Message<T>) -> Result<(), Box<dyn Error + Send + Sync>> {
+    self.listener.on_message(message).await;
+    Ok(())
+  }
+}
+
+impl<T: Serializable + Clone + Send + Sync + 'static> ReliableTopicProxy<T> {
+  pub fn new(
+    base: ProxyBase,
+    partition_id: i32,
+    config: ReliableTopicConfig,
+  ) -> Self {
+    ReliableTopicProxy {
+      ringbuffer_name: format!("{}{}", RINGBUFFER_PREFIX, base.name),
+      base,
+      partition_id,
+      config,
+      runners: Arc::new(Mutex::new(HashMap::new())),
+      phantom: PhantomData::default(),
+    }
+  }
+
+  /// What happens if the ringbuffer is full depends on the configured
+  /// `TopicOverloadPolicy`. Only `Error` makes this fail.
+  pub async fn publish(&self, message: impl Into<T>) -> Result<(), TopicOverloadError> {
+    let mut reliable_topic_message = ReliableTopicMessage::new();
+    reliable_topic_message.publish_time = Some(chrono::Utc::now().timestamp_millis());
+    reliable_topic_message.payload = Some(self.base.to_data(Box::new(message.into())));
+    let message_data = self.base.to_data(Box::new(reliable_topic_message));
+
+    match self.config.overload_policy {
+      TopicOverloadPolicy::DiscardOldest => {
+        self.add(message_data, OverflowPolicy::Overwrite).await;
+      }
+      TopicOverloadPolicy::DiscardNewest => {
+        self.add(message_data, OverflowPolicy::Fail).await;
+      }
+      TopicOverloadPolicy::Block => {
+        let mut backoff = INITIAL_BACKOFF;
+        while self.add(message_data.clone(), OverflowPolicy::Fail).await == -1 {
+          tokio::time::sleep(backoff).await;
+          backoff = min(backoff * 2, MAX_BACKOFF);
+        }
+      }
+      TopicOverloadPolicy::Error => {
+        if self.add(message_data, OverflowPolicy::Fail).await == -1 {
+          return Err(TopicOverloadError { name: self.base.name.clone() });
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Returns the registration id to pass to `remove_message_listener`.
+  pub async fn add_message_listener(&self, listener: impl MessageListener<T>) -> Uuid {
+    self.add_reliable_message_listener(DefaultReliableMessageListener { listener }).await
+  }
+
+  pub async fn add_reliable_message_listener(&self, listener: impl ReliableMessageListener<T>) -> Uuid {
+    let registration_id = Uuid::new_v4();
+    let mut runners = self.runners.lock().await;
+    runners.insert(registration_id, tokio::spawn(self.clone().run_listener(Arc::new(listener), registration_id)));
+    registration_id
+  }
+
+  pub async fn remove_message_listener(&self, registration_id: Uuid) -> bool {
+    match self.runners.lock().await.remove(&registration_id) {
+      Some(runner) => {
+        runner.abort();
+        true
+      }
+      None => false,
+    }
+  }
+
+  async fn run_listener<L: ReliableMessageListener<T>>(self, listener: Arc<L>, registration_id: Uuid) {
+    let mut sequence = match listener.start_sequence() {
+      StartSequence::Oldest => self.head_sequence().await,
+      StartSequence::Latest => self.tail_sequence().await + 1,
+      StartSequence::Stored(sequence) => sequence,
+    };
+
+    'reading: loop {
+      let result = match self.read_many(sequence, 1, self.config.read_batch_size).await {
+        Ok(result) => result,
+        // The sequence was overwritten in the meantime, loss tolerant
+        // listeners go on with the oldest message that is left.
+        Err(error) if error.class_name.as_deref() == Some(STALE_SEQUENCE_EXCEPTION) && listener.is_loss_tolerant() => {
+          sequence = self.head_sequence().await;
+          continue 'reading;
+        }
+        Err(_) => break 'reading,
+      };
+      for (index, item) in result.items.into_iter().enumerate() {
+        let item_sequence = result.item_seqs.as_ref()
+          .and_then(|item_seqs| item_seqs.get(index).copied())
+          .unwrap_or(sequence + index as i64);
+        listener.store_sequence(item_sequence);
+
+        // Messages the client cannot make sense of are skipped.
+        let message = match self.to_message(item).await {
+          Some(message) => message,
+          None => continue,
+        };
+        if let Err(error) = listener.on_message(message).await {
+          if listener.is_terminal(error.as_ref()) {
+            break 'reading;
+          }
+        }
+      }
+      sequence = result.next_seq;
+    }
+
+    self.runners.lock().await.remove(&registration_id);
+  }
+
+  async fn to_message(&self, item: HeapData) -> Option<Message<T>> {
+    let reliable_topic_message = *self.base.serialization_service.to_identified::<ReliableTopicMessage>(item);
+    let payload = reliable_topic_message.payload?;
+    let publish_time = reliable_topic_message.publish_time?;
+    let member = match &reliable_topic_message.publisher_address {
+      Some(publisher_address) => self.base.cluster_service.get_members(None).await.into_iter()
+        .find(|member| member.address == **publisher_address),
+      None => None,
+    };
+    let message_object = *self.base.serialization_service.to_object(payload).await;
+    Some(Message::new(self.base.name.clone(), message_object, publish_time, member))
+  }
+
+  async fn add(&self, message_data: HeapData, overflow_policy: OverflowPolicy) -> i64 {
+    let ringbuffer_name = self.ringbuffer_name.clone();
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |_| Box::pin({
+          let ringbuffer_name = ringbuffer_name.clone();
+          let message_data = message_data.clone();
+          async move {
+            RingbufferAddCodec::encode_request(&ringbuffer_name, &overflow_policy.id(), &message_data).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferAddCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn head_sequence(&self) -> i64 {
+    let ringbuffer_name = self.ringbuffer_name.clone();
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |_| Box::pin({
+          let ringbuffer_name = ringbuffer_name.clone();
+          async move {
+            RingbufferHeadSequenceCodec::encode_request(&ringbuffer_name).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferHeadSequenceCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn tail_sequence(&self) -> i64 {
+    let ringbuffer_name = self.ringbuffer_name.clone();
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |_| Box::pin({
+          let ringbuffer_name = ringbuffer_name.clone();
+          async move {
+            RingbufferTailSequenceCodec::encode_request(&ringbuffer_name).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferTailSequenceCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+
+  async fn read_many(&self, start_sequence: i64, min_count: i32, max_count: i32) -> Result<RingbufferReadManyResponseParams, InvocationError> {
+    let ringbuffer_name = self.ringbuffer_name.clone();
+    self.base.try_encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |_| Box::pin({
+          let ringbuffer_name = ringbuffer_name.clone();
+          async move {
+            RingbufferReadManyCodec::encode_request(&ringbuffer_name, &start_sequence, &min_count, &max_count, &None).await
+          }
+        })
+      }),
+      Box::pin(|mut response| Box::pin(async move { Box::new(Box::new(RingbufferReadManyCodec::decode_response(&mut response).await)) })),
+    ).await
+  }
+}
+
+impl<T: Clone + Send + Sync + Serializable + 'static> Proxy for ReliableTopicProxy<T> {
+  const SERVICE_NAME: &'static str = "hz:impl:reliableTopicService";
+  fn get_proxies() -> Arc<RwLock<HashMap<String, Box<MaybeFuture<Self>>>>> {
+    lazy_static! {
+      static ref PROXIES: Arc<RwLock<HashMap<String, Box<dyn AnySend>>>> = Arc::new(RwLock::new(HashMap::new()));
+    }
+    unsafe { transmute(PROXIES.clone()) }
+  }
+  fn create_proxy(base: ProxyBase) -> Pin<Box<dyn Future<Output=Self> + Send + Sync>> {
+    Box::pin(async move {
+      let partition_id = base.get_partition_id_for_object(&format!("{}{}", RINGBUFFER_PREFIX, base.name)).await;
+      let config = base.config.get_reliable_topic_config(&base.name).await;
+      Self::new(base, partition_id, config)
+    })
+  }
+}
+
+impl<T: Send + Sync + Serializable + 'static> HasProxyBase for ReliableTopicProxy<T> {
+  fn get_proxy_base(&self) -> &ProxyBase {
+    &self.base
+  }
+}
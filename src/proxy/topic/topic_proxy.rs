@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::transmute;
+use std::pin::Pin;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use crate::codec::topic_add_message_listener_codec::TopicAddMessageListenerCodec;
+use crate::codec::topic_publish_all_codec::TopicPublishAllCodec;
+use crate::codec::topic_publish_codec::TopicPublishCodec;
+use crate::codec::topic_remove_message_listener_codec::TopicRemoveMessageListenerCodec;
+use crate::listener::message_codec::ListenerMessageCodec;
+use crate::protocol::client_message::ClientMessage;
+use crate::proxy::base::{HasProxyBase, ProxyBase};
+use crate::proxy::map_proxy::AnySend;
+use crate::proxy::Proxy;
+use crate::proxy::topic::message::Message;
+use crate::proxy::topic::message_listener::MessageListener;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializable::Serializable;
+use crate::util::maybe_future::MaybeFuture;
+
+#[derive(Clone)]
+pub struct TopicProxy<T: Serializable> {
+  base: ProxyBase,
+  partition_id: i32,
+  phantom: PhantomData<T>,
+}
+
+#[derive(Clone)]
+struct TopicMessageListenerCodec {
+  name: String,
+}
+
+impl TopicMessageListenerCodec {
+  pub fn new(name: String) -> Self {
+    Self {
+      name,
+    }
+  }
+}
+
+impl ListenerMessageCodec for TopicMessageListenerCodec {
+  fn encode_add_request<'a>(&'a self, local_only: &'a bool) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    TopicAddMessageListenerCodec::encode_request(&self.name, local_only)
+  }
+
+  fn decode_add_response<'a>(&'a self, client_message: &'a mut ClientMessage) -> Pin<Box<dyn Future<Output=Uuid> + Send + Sync + 'a>> {
+    TopicAddMessageListenerCodec::decode_response(client_message)
+  }
+
+  fn encode_remove_request<'a>(&'a self, registration_id: &'a Uuid) -> Pin<Box<dyn Future<Output=ClientMessage> + Send + Sync + 'a>> {
+    TopicRemoveMessageListenerCodec::encode_request(&self.name, registration_id)
+  }
+}
+
+impl<T: Serializable + Clone + Send + Sync + 'static> TopicProxy<T> {
+  pub fn new(
+    base: ProxyBase,
+    partition_id: i32,
+  ) -> Self {
+    TopicProxy {
+      base,
+      partition_id,
+      phantom: PhantomData::default(),
+    }
+  }
+
+  pub async fn publish(&self, message: impl Into<T>) {
+    let message_data = self.base.to_data(Box::new(message.into()));
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let message_data = message_data.clone();
+          async move {
+            TopicPublishCodec::encode_request(&name, &message_data).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Publishes the messages in one request, keeping their order.
+  pub async fn publish_all(&self, messages: impl IntoIterator<Item=T>) {
+    let messages_data: Vec<HeapData> = messages.into_iter().map(|message| self.base.to_data(Box::new(message))).collect();
+    self.base.encode_invoke_on_partition(
+      self.partition_id,
+      Box::pin({
+        move |name| Box::pin({
+          let messages_data = messages_data.clone();
+          async move {
+            TopicPublishAllCodec::encode_request(&name, &messages_data).await
+          }
+        })
+      }),
+      Box::pin(|_| Box::pin(async move { Box::new(Box::new(())) })),
+    ).await
+  }
+
+  /// Returns the registration id to pass to `remove_message_listener`.
+  pub async fn add_message_listener(&self, listener: impl MessageListener<T>) -> Uuid {
+    let codec = TopicMessageListenerCodec::new(self.base.name.clone());
+    let listener = Arc::new(listener);
+    let base = self.base.clone();
+    self.base.listener_service.register_listener(codec.clone(), {
+      move |mut client_message| {
+        let listener = listener.clone();
+        let base = base.clone();
+        Box::pin(async move {
+          TopicAddMessageListenerCodec::handle(&mut client_message, Some(Box::pin({
+            move |item, publish_time, uuid| {
+              let listener = listener.clone();
+              let base = base.clone();
+              Box::pin(async move {
+                let member = base.cluster_service.get_member(uuid).await;
+                let message_object = *base.serialization_service.to_object(item).await;
+                listener.on_message(Message::new(base.name.clone(), message_object, publish_time, member)).await;
+              })
+            }
+          }))).await;
+        })
+      }
+    }).await
+  }
+
+  pub async fn remove_message_listener(&self, registration_id: Uuid) -> bool {
+    self.base.listener_service.deregister_listener(registration_id).await
+  }
+}
+
+impl<T: Clone + Send + Sync + Serializable + 'static> Proxy for TopicProxy<T> {
+  const SERVICE_NAME: &'static str = "hz:impl:topicService";
+  fn get_proxies() -> Arc<RwLock<HashMap<String, Box<MaybeFuture<Self>>>>> {
+    lazy_static! {
+      static ref PROXIES: Arc<RwLock<HashMap<String, Box<dyn AnySend>>>> = Arc::new(RwLock::new(HashMap::new()));
+    }
+    unsafe { transmute(PROXIES.clone()) }
+  }
+  fn create_proxy(base: ProxyBase) -> Pin<Box<dyn Future<Output=Self> + Send + Sync>> {
+    Box::pin(async move {
+      let partition_id = base.get_partition_id_for_name().await;
+      Self::new(base, partition_id)
+    })
+  }
+}
+
+impl<T: Send + Sync + Serializable + 'static> HasProxyBase for TopicProxy<T> {
+  fn get_proxy_base(&self) -> &ProxyBase {
+    &self.base
+  }
+}
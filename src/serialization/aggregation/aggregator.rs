@@ -1,5 +1,374 @@
-use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableSerialization};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use num_bigint::BigInt;
+use crate::core::big_decimal::BigDecimal;
+use crate::serialization::aggregation::aggregator_constants::*;
+use crate::serialization::data::{DataInput, DataOutput};
+use crate::serialization::data::object_data_input::ObjectDataInput;
+use crate::serialization::data::object_data_output::ObjectDataOutput;
+use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableInfo, IdentifiedDataSerializableSerialization, IdentifiedWrappedSerializer, Serializable};
+use crate::serialization::query_result::QueryResult;
+use crate::serialization::serializer::Serializer;
+use crate::serialization::service::SerializationServiceV1;
+use crate::serialization::value::Value;
+
+/// The aggregator state as the members know it. The client only ever sends
+/// the initial state, so the accumulators are always zero or null here.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Aggregation {
+  BigDecimalAvg { attribute_path: Option<String>, sum: Value, count: i64 },
+  BigDecimalSum { attribute_path: Option<String>, sum: Value },
+  BigIntegerAvg { attribute_path: Option<String>, sum: Value, count: i64 },
+  BigIntegerSum { attribute_path: Option<String>, sum: Value },
+  Count { attribute_path: Option<String>, count: i64 },
+  Distinct { attribute_path: Option<String>, values: Vec<Value> },
+  DoubleAvg { attribute_path: Option<String>, sum: f64, count: i64 },
+  DoubleSum { attribute_path: Option<String>, sum: f64 },
+  FixedSum { attribute_path: Option<String>, sum: i64 },
+  FloatingPointSum { attribute_path: Option<String>, sum: f64 },
+  IntegerAvg { attribute_path: Option<String>, sum: i64, count: i64 },
+  IntegerSum { attribute_path: Option<String>, sum: i64 },
+  LongAvg { attribute_path: Option<String>, sum: i64, count: i64 },
+  LongSum { attribute_path: Option<String>, sum: i64 },
+  Max { attribute_path: Option<String>, max: Value },
+  Min { attribute_path: Option<String>, min: Value },
+  NumberAvg { attribute_path: Option<String>, sum: f64, count: i64 },
+  /// An aggregator the client has no counterpart for. Its fields are skipped.
+  Unknown { class_id: i32 },
+}
+
+impl Aggregation {
+  fn empty(class_id: i32) -> Self {
+    match class_id {
+      BIG_DECIMAL_AVG_AGGREGATOR_CLASS_ID => Aggregation::BigDecimalAvg { attribute_path: None, sum: Value::Null, count: 0 },
+      BIG_DECIMAL_SUM_AGGREGATOR_CLASS_ID => Aggregation::BigDecimalSum { attribute_path: None, sum: Value::Null },
+      BIG_INTEGER_AVG_AGGREGATOR_CLASS_ID => Aggregation::BigIntegerAvg { attribute_path: None, sum: Value::Null, count: 0 },
+      BIG_INTEGER_SUM_AGGREGATOR_CLASS_ID => Aggregation::BigIntegerSum { attribute_path: None, sum: Value::Null },
+      COUNT_AGGREGATOR_CLASS_ID => Aggregation::Count { attribute_path: None, count: 0 },
+      DISTINCT_AGGREGATOR_CLASS_ID => Aggregation::Distinct { attribute_path: None, values: vec![] },
+      DOUBLE_AVG_AGGREGATOR_CLASS_ID => Aggregation::DoubleAvg { attribute_path: None, sum: 0.0, count: 0 },
+      DOUBLE_SUM_AGGREGATOR_CLASS_ID => Aggregation::DoubleSum { attribute_path: None, sum: 0.0 },
+      FIXED_SUM_AGGREGATOR_CLASS_ID => Aggregation::FixedSum { attribute_path: None, sum: 0 },
+      FLOATING_POINT_SUM_AGGREGATOR_CLASS_ID => Aggregation::FloatingPointSum { attribute_path: None, sum: 0.0 },
+      INTEGER_AVG_AGGREGATOR_CLASS_ID => Aggregation::IntegerAvg { attribute_path: None, sum: 0, count: 0 },
+      INTEGER_SUM_AGGREGATOR_CLASS_ID => Aggregation::IntegerSum { attribute_path: None, sum: 0 },
+      LONG_AVG_AGGREGATOR_CLASS_ID => Aggregation::LongAvg { attribute_path: None, sum: 0, count: 0 },
+      LONG_SUM_AGGREGATOR_CLASS_ID => Aggregation::LongSum { attribute_path: None, sum: 0 },
+      MAX_AGGREGATOR_CLASS_ID => Aggregation::Max { attribute_path: None, max: Value::Null },
+      MIN_AGGREGATOR_CLASS_ID => Aggregation::Min { attribute_path: None, min: Value::Null },
+      NUMBER_AVG_AGGREGATOR_CLASS_ID => Aggregation::NumberAvg { attribute_path: None, sum: 0.0, count: 0 },
+      class_id => Aggregation::Unknown { class_id },
+    }
+  }
+
+  fn attribute_path(&mut self) -> Option<&mut Option<String>> {
+    match self {
+      Aggregation::BigDecimalAvg { attribute_path, .. }
+      | Aggregation::BigDecimalSum { attribute_path, .. }
+      | Aggregation::BigIntegerAvg { attribute_path, .. }
+      | Aggregation::BigIntegerSum { attribute_path, .. }
+      | Aggregation::Count { attribute_path, .. }
+      | Aggregation::Distinct { attribute_path, .. }
+      | Aggregation::DoubleAvg { attribute_path, .. }
+      | Aggregation::DoubleSum { attribute_path, .. }
+      | Aggregation::FixedSum { attribute_path, .. }
+      | Aggregation::FloatingPointSum { attribute_path, .. }
+      | Aggregation::IntegerAvg { attribute_path, .. }
+      | Aggregation::IntegerSum { attribute_path, .. }
+      | Aggregation::LongAvg { attribute_path, .. }
+      | Aggregation::LongSum { attribute_path, .. }
+      | Aggregation::Max { attribute_path, .. }
+      | Aggregation::Min { attribute_path, .. }
+      | Aggregation::NumberAvg { attribute_path, .. } => Some(attribute_path),
+      Aggregation::Unknown { .. } => None,
+    }
+  }
+}
+
+impl IdentifiedDataSerializableInfo for Aggregation {
+  fn factory_id(&self) -> i32 {
+    AGGREGATOR_FACTORY_ID
+  }
+
+  fn class_id(&self) -> i32 {
+    match self {
+      Aggregation::BigDecimalAvg { .. } => BIG_DECIMAL_AVG_AGGREGATOR_CLASS_ID,
+      Aggregation::BigDecimalSum { .. } => BIG_DECIMAL_SUM_AGGREGATOR_CLASS_ID,
+      Aggregation::BigIntegerAvg { .. } => BIG_INTEGER_AVG_AGGREGATOR_CLASS_ID,
+      Aggregation::BigIntegerSum { .. } => BIG_INTEGER_SUM_AGGREGATOR_CLASS_ID,
+      Aggregation::Count { .. } => COUNT_AGGREGATOR_CLASS_ID,
+      Aggregation::Distinct { .. } => DISTINCT_AGGREGATOR_CLASS_ID,
+      Aggregation::DoubleAvg { .. } => DOUBLE_AVG_AGGREGATOR_CLASS_ID,
+      Aggregation::DoubleSum { .. } => DOUBLE_SUM_AGGREGATOR_CLASS_ID,
+      Aggregation::FixedSum { .. } => FIXED_SUM_AGGREGATOR_CLASS_ID,
+      Aggregation::FloatingPointSum { .. } => FLOATING_POINT_SUM_AGGREGATOR_CLASS_ID,
+      Aggregation::IntegerAvg { .. } => INTEGER_AVG_AGGREGATOR_CLASS_ID,
+      Aggregation::IntegerSum { .. } => INTEGER_SUM_AGGREGATOR_CLASS_ID,
+      Aggregation::LongAvg { .. } => LONG_AVG_AGGREGATOR_CLASS_ID,
+      Aggregation::LongSum { .. } => LONG_SUM_AGGREGATOR_CLASS_ID,
+      Aggregation::Max { .. } => MAX_AGGREGATOR_CLASS_ID,
+      Aggregation::Min { .. } => MIN_AGGREGATOR_CLASS_ID,
+      Aggregation::NumberAvg { .. } => NUMBER_AVG_AGGREGATOR_CLASS_ID,
+      Aggregation::Unknown { class_id } => *class_id,
+    }
+  }
+}
+
+impl IdentifiedDataSerializableSerialization for Aggregation {
+  fn read_data(&mut self, input: &mut ObjectDataInput) {
+    if let Some(attribute_path) = self.attribute_path() {
+      *attribute_path = input.read_string();
+    }
+    match self {
+      Aggregation::BigDecimalAvg { sum, count, .. } | Aggregation::BigIntegerAvg { sum, count, .. } => {
+        *sum = Value::read(input);
+        *count = input.read_long();
+      }
+      Aggregation::BigDecimalSum { sum, .. } | Aggregation::BigIntegerSum { sum, .. } => {
+        *sum = Value::read(input);
+      }
+      Aggregation::Count { count, .. } => {
+        *count = input.read_long();
+      }
+      Aggregation::Distinct { values, .. } => {
+        let len = input.read_int();
+        *values = (0..len).map(|_| Value::read(input)).collect();
+      }
+      Aggregation::DoubleAvg { sum, count, .. } | Aggregation::NumberAvg { sum, count, .. } => {
+        *sum = input.read_double();
+        *count = input.read_long();
+      }
+      Aggregation::DoubleSum { sum, .. } | Aggregation::FloatingPointSum { sum, .. } => {
+        *sum = input.read_double();
+      }
+      Aggregation::IntegerAvg { sum, count, .. } | Aggregation::LongAvg { sum, count, .. } => {
+        *sum = input.read_long();
+        *count = input.read_long();
+      }
+      Aggregation::FixedSum { sum, .. } | Aggregation::IntegerSum { sum, .. } | Aggregation::LongSum { sum, .. } => {
+        *sum = input.read_long();
+      }
+      Aggregation::Max { max: value, .. } | Aggregation::Min { min: value, .. } => {
+        *value = Value::read(input);
+      }
+      Aggregation::Unknown { .. } => {}
+    }
+  }
+
+  fn write_data(&mut self, output: &mut ObjectDataOutput) {
+    if let Some(attribute_path) = self.attribute_path() {
+      output.write_string(attribute_path.as_ref());
+    }
+    match self {
+      Aggregation::BigDecimalAvg { sum, count, .. } | Aggregation::BigIntegerAvg { sum, count, .. } => {
+        sum.write(output);
+        output.write_long(*count);
+      }
+      Aggregation::BigDecimalSum { sum, .. } | Aggregation::BigIntegerSum { sum, .. } => {
+        sum.write(output);
+      }
+      Aggregation::Count { count, .. } => {
+        output.write_long(*count);
+      }
+      Aggregation::Distinct { values, .. } => {
+        output.write_int(values.len() as i32);
+        for value in values {
+          value.write(output);
+        }
+      }
+      Aggregation::DoubleAvg { sum, count, .. } | Aggregation::NumberAvg { sum, count, .. } => {
+        output.write_double(*sum);
+        output.write_long(*count);
+      }
+      Aggregation::DoubleSum { sum, .. } | Aggregation::FloatingPointSum { sum, .. } => {
+        output.write_double(*sum);
+      }
+      Aggregation::IntegerAvg { sum, count, .. } | Aggregation::LongAvg { sum, count, .. } => {
+        output.write_long(*sum);
+        output.write_long(*count);
+      }
+      Aggregation::FixedSum { sum, .. } | Aggregation::IntegerSum { sum, .. } | Aggregation::LongSum { sum, .. } => {
+        output.write_long(*sum);
+      }
+      Aggregation::Max { max: value, .. } | Aggregation::Min { min: value, .. } => {
+        value.write(output);
+      }
+      Aggregation::Unknown { .. } => {}
+    }
+  }
+}
+
+impl Serializable for Aggregation {
+  fn get_serializer(&self, service: &SerializationServiceV1) -> Arc<dyn Serializer<Box<Self>>> {
+    Arc::new(IdentifiedWrappedSerializer::new(service.identified_serializer.clone()))
+  }
+}
+
+/// The set the distinct aggregator answers with.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CanonicalizingSet {
+  pub values: Vec<Value>,
+}
+
+impl IdentifiedDataSerializableInfo for CanonicalizingSet {
+  fn factory_id(&self) -> i32 {
+    AGGREGATOR_FACTORY_ID
+  }
+
+  fn class_id(&self) -> i32 {
+    CANONICALIZING_SET_CLASS_ID
+  }
+}
+
+impl IdentifiedDataSerializableSerialization for CanonicalizingSet {
+  fn read_data(&mut self, input: &mut ObjectDataInput) {
+    let len = input.read_int();
+    self.values = (0..len).map(|_| Value::read(input)).collect();
+  }
+
+  fn write_data(&mut self, output: &mut ObjectDataOutput) {
+    output.write_int(self.values.len() as i32);
+    for value in &self.values {
+      value.write(output);
+    }
+  }
+}
+
+/// An aggregation together with the type of its result, e.g.
+/// `Aggregator::integer_avg("age")` yields an `Option<f64>` that is `None`
+/// when no entry matched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aggregator<R> {
+  aggregation: Aggregation,
+  phantom: PhantomData<fn() -> R>,
+}
+
+impl<R: QueryResult> Aggregator<R> {
+  fn new(aggregation: Aggregation) -> Self {
+    Self {
+      aggregation,
+      phantom: PhantomData::default(),
+    }
+  }
+
+  pub fn get_aggregation(&self) -> &Aggregation {
+    &self.aggregation
+  }
+
+  pub fn into_aggregation(self) -> Aggregation {
+    self.aggregation
+  }
+}
+
+impl Aggregator<i64> {
+  pub fn count() -> Self {
+    Self::new(Aggregation::Count { attribute_path: None, count: 0 })
+  }
+
+  pub fn count_of(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::Count { attribute_path: Some(attribute_path.to_string()), count: 0 })
+  }
+
+  pub fn integer_sum(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::IntegerSum { attribute_path: Some(attribute_path.to_string()), sum: 0 })
+  }
+
+  pub fn long_sum(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::LongSum { attribute_path: Some(attribute_path.to_string()), sum: 0 })
+  }
+
+  pub fn fixed_point_sum(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::FixedSum { attribute_path: Some(attribute_path.to_string()), sum: 0 })
+  }
+}
+
+impl Aggregator<f64> {
+  pub fn double_sum(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::DoubleSum { attribute_path: Some(attribute_path.to_string()), sum: 0.0 })
+  }
+
+  pub fn floating_point_sum(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::FloatingPointSum { attribute_path: Some(attribute_path.to_string()), sum: 0.0 })
+  }
+}
+
+impl Aggregator<Option<f64>> {
+  pub fn integer_avg(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::IntegerAvg { attribute_path: Some(attribute_path.to_string()), sum: 0, count: 0 })
+  }
+
+  pub fn long_avg(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::LongAvg { attribute_path: Some(attribute_path.to_string()), sum: 0, count: 0 })
+  }
+
+  pub fn double_avg(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::DoubleAvg { attribute_path: Some(attribute_path.to_string()), sum: 0.0, count: 0 })
+  }
+
+  pub fn number_avg(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::NumberAvg { attribute_path: Some(attribute_path.to_string()), sum: 0.0, count: 0 })
+  }
+}
+
+impl Aggregator<BigDecimal> {
+  pub fn big_decimal_sum(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::BigDecimalSum {
+      attribute_path: Some(attribute_path.to_string()),
+      sum: Value::BigDecimal(BigDecimal::new(BigInt::from(0), 0)),
+    })
+  }
+}
+
+impl Aggregator<Option<BigDecimal>> {
+  pub fn big_decimal_avg(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::BigDecimalAvg {
+      attribute_path: Some(attribute_path.to_string()),
+      sum: Value::BigDecimal(BigDecimal::new(BigInt::from(0), 0)),
+      count: 0,
+    })
+  }
+
+  pub fn big_integer_avg(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::BigIntegerAvg {
+      attribute_path: Some(attribute_path.to_string()),
+      sum: Value::BigDecimal(BigDecimal::new(BigInt::from(0), 0)),
+      count: 0,
+    })
+  }
+}
+
+impl Aggregator<BigInt> {
+  pub fn big_integer_sum(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::BigIntegerSum {
+      attribute_path: Some(attribute_path.to_string()),
+      sum: Value::BigInteger(BigInt::from(0)),
+    })
+  }
+}
+
+impl Aggregator<Vec<Value>> {
+  pub fn distinct() -> Self {
+    Self::new(Aggregation::Distinct { attribute_path: None, values: vec![] })
+  }
+
+  pub fn distinct_of(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::Distinct { attribute_path: Some(attribute_path.to_string()), values: vec![] })
+  }
+}
+
+impl<R: QueryResult> Aggregator<Option<R>> {
+  pub fn max(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::Max { attribute_path: Some(attribute_path.to_string()), max: Value::Null })
+  }
+
+  pub fn min(attribute_path: impl ToString) -> Self {
+    Self::new(Aggregation::Min { attribute_path: Some(attribute_path.to_string()), min: Value::Null })
+  }
+}
 
 pub fn aggregator_factory(class_id: i32) -> Box<dyn IdentifiedDataSerializable> {
-   todo!()
-}
\ No newline at end of file
+  match class_id {
+    CANONICALIZING_SET_CLASS_ID => Box::new(CanonicalizingSet::default()),
+    class_id => Box::new(Aggregation::empty(class_id)),
+  }
+}
@@ -1 +1,20 @@
-pub const AGGREGATOR_FACTORY_ID: i32 = -29;
\ No newline at end of file
+pub const AGGREGATOR_FACTORY_ID: i32 = -29;
+
+pub const BIG_DECIMAL_AVG_AGGREGATOR_CLASS_ID: i32 = 0;
+pub const BIG_DECIMAL_SUM_AGGREGATOR_CLASS_ID: i32 = 1;
+pub const BIG_INTEGER_AVG_AGGREGATOR_CLASS_ID: i32 = 2;
+pub const BIG_INTEGER_SUM_AGGREGATOR_CLASS_ID: i32 = 3;
+pub const COUNT_AGGREGATOR_CLASS_ID: i32 = 4;
+pub const DISTINCT_AGGREGATOR_CLASS_ID: i32 = 5;
+pub const DOUBLE_AVG_AGGREGATOR_CLASS_ID: i32 = 6;
+pub const DOUBLE_SUM_AGGREGATOR_CLASS_ID: i32 = 7;
+pub const FIXED_SUM_AGGREGATOR_CLASS_ID: i32 = 8;
+pub const FLOATING_POINT_SUM_AGGREGATOR_CLASS_ID: i32 = 9;
+pub const INTEGER_AVG_AGGREGATOR_CLASS_ID: i32 = 10;
+pub const INTEGER_SUM_AGGREGATOR_CLASS_ID: i32 = 11;
+pub const LONG_AVG_AGGREGATOR_CLASS_ID: i32 = 12;
+pub const LONG_SUM_AGGREGATOR_CLASS_ID: i32 = 13;
+pub const MAX_AGGREGATOR_CLASS_ID: i32 = 14;
+pub const MIN_AGGREGATOR_CLASS_ID: i32 = 15;
+pub const NUMBER_AVG_AGGREGATOR_CLASS_ID: i32 = 16;
+pub const CANONICALIZING_SET_CLASS_ID: i32 = 19;
@@ -3,9 +3,9 @@ pub mod object_data_output;
 
 use std::any::Any;
 use crate::serialization::heap_data::HeapData;
-use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableSerialization};
+use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableInfo, IdentifiedDataSerializableSerialization};
 
-pub trait DataOutputWriteObject<T: IdentifiedDataSerializableSerialization> {
+pub trait DataOutputWriteObject<T: IdentifiedDataSerializableSerialization + IdentifiedDataSerializableInfo> {
   fn write_object(&mut self, object: &mut T);
 }
 
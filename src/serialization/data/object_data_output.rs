@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use crate::serialization::data::{DataOutput, DataOutputWriteObject, PositionalOutput};
 use crate::serialization::heap_data::HeapData;
-use crate::serialization::serializable::IdentifiedDataSerializableSerialization;
+use crate::serialization::serializable::{IdentifiedDataSerializableInfo, IdentifiedDataSerializableSerialization};
 use crate::serialization::service::SerializationServiceV1;
 use crate::util::bits_util::BitsUtil;
 
@@ -26,7 +26,7 @@ impl ObjectDataOutput {
   }
 }
 
-impl<T: IdentifiedDataSerializableSerialization> DataOutputWriteObject<T> for ObjectDataOutput {
+impl<T: IdentifiedDataSerializableSerialization + IdentifiedDataSerializableInfo> DataOutputWriteObject<T> for ObjectDataOutput {
   fn write_object(&mut self, object: &mut T) {
     self.service.clone().write_object(self, object);
   }
@@ -1,7 +1,305 @@
-use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableSerialization};
+use std::sync::Arc;
+use crate::serialization::data::{DataInput, DataOutput, DataOutputWriteObject};
+use crate::serialization::data::object_data_input::ObjectDataInput;
+use crate::serialization::data::object_data_output::ObjectDataOutput;
+use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableInfo, IdentifiedDataSerializableSerialization, IdentifiedWrappedSerializer, Serializable};
+use crate::serialization::serializer::Serializer;
+use crate::serialization::service::SerializationServiceV1;
+use crate::serialization::value::Value;
 
 pub const PREDICATE_FACTORY_ID: i32 = -20;
 
+pub const SQL_PREDICATE_CLASS_ID: i32 = 0;
+pub const AND_PREDICATE_CLASS_ID: i32 = 1;
+pub const BETWEEN_PREDICATE_CLASS_ID: i32 = 2;
+pub const EQUAL_PREDICATE_CLASS_ID: i32 = 3;
+pub const GREATER_LESS_PREDICATE_CLASS_ID: i32 = 4;
+pub const LIKE_PREDICATE_CLASS_ID: i32 = 5;
+pub const ILIKE_PREDICATE_CLASS_ID: i32 = 6;
+pub const IN_PREDICATE_CLASS_ID: i32 = 7;
+pub const INSTANCE_OF_PREDICATE_CLASS_ID: i32 = 8;
+pub const NOT_EQUAL_PREDICATE_CLASS_ID: i32 = 9;
+pub const NOT_PREDICATE_CLASS_ID: i32 = 10;
+pub const OR_PREDICATE_CLASS_ID: i32 = 11;
+pub const REGEX_PREDICATE_CLASS_ID: i32 = 12;
+pub const FALSE_PREDICATE_CLASS_ID: i32 = 13;
+pub const TRUE_PREDICATE_CLASS_ID: i32 = 14;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+  Sql { sql: String },
+  And { predicates: Vec<Predicate> },
+  Between { attribute: String, from: Value, to: Value },
+  Equal { attribute: String, value: Value },
+  GreaterLess { attribute: String, value: Value, equal: bool, less: bool },
+  Like { attribute: String, expression: String },
+  ILike { attribute: String, expression: String },
+  In { attribute: String, values: Vec<Value> },
+  InstanceOf { class_name: String },
+  NotEqual { attribute: String, value: Value },
+  Not { predicate: Box<Predicate> },
+  Or { predicates: Vec<Predicate> },
+  Regex { attribute: String, regex: String },
+  False,
+  True,
+  /// A predicate the client has no counterpart for, e.g. a custom one the
+  /// member sent. Its fields are skipped, so it cannot be sent back.
+  Unknown { factory_id: i32, class_id: i32 },
+}
+
+impl Predicate {
+  pub fn sql(sql: impl ToString) -> Self {
+    Predicate::Sql { sql: sql.to_string() }
+  }
+
+  pub fn and(predicates: Vec<Predicate>) -> Self {
+    Predicate::And { predicates }
+  }
+
+  pub fn between(attribute: impl ToString, from: impl Into<Value>, to: impl Into<Value>) -> Self {
+    Predicate::Between { attribute: attribute.to_string(), from: from.into(), to: to.into() }
+  }
+
+  pub fn equal(attribute: impl ToString, value: impl Into<Value>) -> Self {
+    Predicate::Equal { attribute: attribute.to_string(), value: value.into() }
+  }
+
+  pub fn not_equal(attribute: impl ToString, value: impl Into<Value>) -> Self {
+    Predicate::NotEqual { attribute: attribute.to_string(), value: value.into() }
+  }
+
+  pub fn greater_than(attribute: impl ToString, value: impl Into<Value>) -> Self {
+    Predicate::GreaterLess { attribute: attribute.to_string(), value: value.into(), equal: false, less: false }
+  }
+
+  pub fn greater_equal(attribute: impl ToString, value: impl Into<Value>) -> Self {
+    Predicate::GreaterLess { attribute: attribute.to_string(), value: value.into(), equal: true, less: false }
+  }
+
+  pub fn less_than(attribute: impl ToString, value: impl Into<Value>) -> Self {
+    Predicate::GreaterLess { attribute: attribute.to_string(), value: value.into(), equal: false, less: true }
+  }
+
+  pub fn less_equal(attribute: impl ToString, value: impl Into<Value>) -> Self {
+    Predicate::GreaterLess { attribute: attribute.to_string(), value: value.into(), equal: true, less: true }
+  }
+
+  pub fn like(attribute: impl ToString, expression: impl ToString) -> Self {
+    Predicate::Like { attribute: attribute.to_string(), expression: expression.to_string() }
+  }
+
+  pub fn ilike(attribute: impl ToString, expression: impl ToString) -> Self {
+    Predicate::ILike { attribute: attribute.to_string(), expression: expression.to_string() }
+  }
+
+  pub fn is_in(attribute: impl ToString, values: impl IntoIterator<Item=impl Into<Value>>) -> Self {
+    Predicate::In { attribute: attribute.to_string(), values: values.into_iter().map(Into::into).collect() }
+  }
+
+  pub fn instance_of(class_name: impl ToString) -> Self {
+    Predicate::InstanceOf { class_name: class_name.to_string() }
+  }
+
+  pub fn not(predicate: Predicate) -> Self {
+    Predicate::Not { predicate: Box::new(predicate) }
+  }
+
+  pub fn or(predicates: Vec<Predicate>) -> Self {
+    Predicate::Or { predicates }
+  }
+
+  pub fn regex(attribute: impl ToString, regex: impl ToString) -> Self {
+    Predicate::Regex { attribute: attribute.to_string(), regex: regex.to_string() }
+  }
+
+  pub fn always_false() -> Self {
+    Predicate::False
+  }
+
+  pub fn always_true() -> Self {
+    Predicate::True
+  }
+
+  fn empty(class_id: i32) -> Self {
+    match class_id {
+      SQL_PREDICATE_CLASS_ID => Predicate::Sql { sql: String::new() },
+      AND_PREDICATE_CLASS_ID => Predicate::And { predicates: vec![] },
+      BETWEEN_PREDICATE_CLASS_ID => Predicate::Between { attribute: String::new(), from: Value::Null, to: Value::Null },
+      EQUAL_PREDICATE_CLASS_ID => Predicate::Equal { attribute: String::new(), value: Value::Null },
+      GREATER_LESS_PREDICATE_CLASS_ID => Predicate::GreaterLess { attribute: String::new(), value: Value::Null, equal: false, less: false },
+      LIKE_PREDICATE_CLASS_ID => Predicate::Like { attribute: String::new(), expression: String::new() },
+      ILIKE_PREDICATE_CLASS_ID => Predicate::ILike { attribute: String::new(), expression: String::new() },
+      IN_PREDICATE_CLASS_ID => Predicate::In { attribute: String::new(), values: vec![] },
+      INSTANCE_OF_PREDICATE_CLASS_ID => Predicate::InstanceOf { class_name: String::new() },
+      NOT_EQUAL_PREDICATE_CLASS_ID => Predicate::NotEqual { attribute: String::new(), value: Value::Null },
+      NOT_PREDICATE_CLASS_ID => Predicate::Not { predicate: Box::new(Predicate::True) },
+      OR_PREDICATE_CLASS_ID => Predicate::Or { predicates: vec![] },
+      REGEX_PREDICATE_CLASS_ID => Predicate::Regex { attribute: String::new(), regex: String::new() },
+      FALSE_PREDICATE_CLASS_ID => Predicate::False,
+      TRUE_PREDICATE_CLASS_ID => Predicate::True,
+      class_id => Predicate::Unknown { factory_id: PREDICATE_FACTORY_ID, class_id },
+    }
+  }
+
+  fn read_nested(input: &mut ObjectDataInput) -> Self {
+    // serializer id and identified flag
+    input.read_int();
+    input.read_boolean();
+    let factory_id = input.read_int();
+    let class_id = input.read_int();
+    if factory_id != PREDICATE_FACTORY_ID {
+      return Predicate::Unknown { factory_id, class_id };
+    }
+    let mut predicate = Self::empty(class_id);
+    predicate.read_data(input);
+    predicate
+  }
+
+  fn read_predicates(input: &mut ObjectDataInput) -> Vec<Predicate> {
+    let len = input.read_int();
+    (0..len).map(|_| Self::read_nested(input)).collect()
+  }
+
+  fn write_predicates(output: &mut ObjectDataOutput, predicates: &mut Vec<Predicate>) {
+    output.write_int(predicates.len() as i32);
+    for predicate in predicates {
+      output.write_object(predicate);
+    }
+  }
+}
+
+impl IdentifiedDataSerializableInfo for Predicate {
+  fn factory_id(&self) -> i32 {
+    match self {
+      Predicate::Unknown { factory_id, .. } => *factory_id,
+      _ => PREDICATE_FACTORY_ID,
+    }
+  }
+
+  fn class_id(&self) -> i32 {
+    match self {
+      Predicate::Sql { .. } => SQL_PREDICATE_CLASS_ID,
+      Predicate::And { .. } => AND_PREDICATE_CLASS_ID,
+      Predicate::Between { .. } => BETWEEN_PREDICATE_CLASS_ID,
+      Predicate::Equal { .. } => EQUAL_PREDICATE_CLASS_ID,
+      Predicate::GreaterLess { .. } => GREATER_LESS_PREDICATE_CLASS_ID,
+      Predicate::Like { .. } => LIKE_PREDICATE_CLASS_ID,
+      Predicate::ILike { .. } => ILIKE_PREDICATE_CLASS_ID,
+      Predicate::In { .. } => IN_PREDICATE_CLASS_ID,
+      Predicate::InstanceOf { .. } => INSTANCE_OF_PREDICATE_CLASS_ID,
+      Predicate::NotEqual { .. } => NOT_EQUAL_PREDICATE_CLASS_ID,
+      Predicate::Not { .. } => NOT_PREDICATE_CLASS_ID,
+      Predicate::Or { .. } => OR_PREDICATE_CLASS_ID,
+      Predicate::Regex { .. } => REGEX_PREDICATE_CLASS_ID,
+      Predicate::False => FALSE_PREDICATE_CLASS_ID,
+      Predicate::True => TRUE_PREDICATE_CLASS_ID,
+      Predicate::Unknown { class_id, .. } => *class_id,
+    }
+  }
+}
+
+impl IdentifiedDataSerializableSerialization for Predicate {
+  fn read_data(&mut self, input: &mut ObjectDataInput) {
+    match self {
+      Predicate::Sql { sql } => {
+        *sql = input.read_string().unwrap();
+      }
+      Predicate::And { predicates } | Predicate::Or { predicates } => {
+        *predicates = Self::read_predicates(input);
+      }
+      Predicate::Between { attribute, from, to } => {
+        *attribute = input.read_string().unwrap();
+        *to = Value::read(input);
+        *from = Value::read(input);
+      }
+      Predicate::Equal { attribute, value } | Predicate::NotEqual { attribute, value } => {
+        *attribute = input.read_string().unwrap();
+        *value = Value::read(input);
+      }
+      Predicate::GreaterLess { attribute, value, equal, less } => {
+        *attribute = input.read_string().unwrap();
+        *value = Value::read(input);
+        *equal = input.read_boolean();
+        *less = input.read_boolean();
+      }
+      Predicate::Like { attribute, expression } | Predicate::ILike { attribute, expression } => {
+        *attribute = input.read_string().unwrap();
+        *expression = input.read_string().unwrap();
+      }
+      Predicate::In { attribute, values } => {
+        *attribute = input.read_string().unwrap();
+        let len = input.read_int();
+        *values = (0..len).map(|_| Value::read(input)).collect();
+      }
+      Predicate::InstanceOf { class_name } => {
+        *class_name = input.read_string().unwrap();
+      }
+      Predicate::Not { predicate } => {
+        *predicate = Box::new(Self::read_nested(input));
+      }
+      Predicate::Regex { attribute, regex } => {
+        *attribute = input.read_string().unwrap();
+        *regex = input.read_string().unwrap();
+      }
+      Predicate::False | Predicate::True | Predicate::Unknown { .. } => {}
+    }
+  }
+
+  fn write_data(&mut self, output: &mut ObjectDataOutput) {
+    match self {
+      Predicate::Sql { sql } => {
+        output.write_string(Some(sql));
+      }
+      Predicate::And { predicates } | Predicate::Or { predicates } => {
+        Self::write_predicates(output, predicates);
+      }
+      Predicate::Between { attribute, from, to } => {
+        output.write_string(Some(attribute));
+        to.write(output);
+        from.write(output);
+      }
+      Predicate::Equal { attribute, value } | Predicate::NotEqual { attribute, value } => {
+        output.write_string(Some(attribute));
+        value.write(output);
+      }
+      Predicate::GreaterLess { attribute, value, equal, less } => {
+        output.write_string(Some(attribute));
+        value.write(output);
+        output.write_boolean(*equal);
+        output.write_boolean(*less);
+      }
+      Predicate::Like { attribute, expression } | Predicate::ILike { attribute, expression } => {
+        output.write_string(Some(attribute));
+        output.write_string(Some(expression));
+      }
+      Predicate::In { attribute, values } => {
+        output.write_string(Some(attribute));
+        output.write_int(values.len() as i32);
+        for value in values {
+          value.write(output);
+        }
+      }
+      Predicate::InstanceOf { class_name } => {
+        output.write_string(Some(class_name));
+      }
+      Predicate::Not { predicate } => {
+        output.write_object(predicate.as_mut());
+      }
+      Predicate::Regex { attribute, regex } => {
+        output.write_string(Some(attribute));
+        output.write_string(Some(regex));
+      }
+      Predicate::False | Predicate::True | Predicate::Unknown { .. } => {}
+    }
+  }
+}
+
+impl Serializable for Predicate {
+  fn get_serializer(&self, service: &SerializationServiceV1) -> Arc<dyn Serializer<Box<Self>>> {
+    Arc::new(IdentifiedWrappedSerializer::new(service.identified_serializer.clone()))
+  }
+}
+
 pub fn predicate_factory(class_id: i32) -> Box<dyn IdentifiedDataSerializable> {
-  todo!()
-}
\ No newline at end of file
+  Box::new(Predicate::empty(class_id))
+}
@@ -11,4 +11,8 @@ pub mod schema_service;
 pub mod serializable;
 pub mod default_predicates;
 pub mod cluster_data_factory;
-pub mod aggregation;
\ No newline at end of file
+pub mod aggregation;
+pub mod value;
+pub mod paging_predicate;
+pub mod projection;
+pub mod query_result;
@@ -0,0 +1,120 @@
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use crate::protocol::anchor_data_list_holder::AnchorDataListHolder;
+use crate::protocol::paging_predicate_holder::PagingPredicateHolder;
+use crate::proxy::server_side_function::ServerSideFunction;
+use crate::serialization::default_predicates::Predicate;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::service::SerializationServiceV1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IterationType {
+  Key,
+  Value,
+  Entry,
+}
+
+impl IterationType {
+  pub fn id(&self) -> u8 {
+    match self {
+      IterationType::Key => 0,
+      IterationType::Value => 1,
+      IterationType::Entry => 2,
+    }
+  }
+}
+
+/// Splits the result of a query into pages of `page_size` entries. The
+/// member sorts the result with the comparator (or by key if none is set)
+/// and uses the anchors returned with each page to resume from there.
+#[derive(Clone)]
+pub struct PagingPredicate {
+  predicate: Option<Predicate>,
+  comparator: Option<ServerSideFunction>,
+  page_size: i32,
+  page: i32,
+  iteration_type: IterationType,
+  anchor_list: Vec<(i32, (HeapData, HeapData))>,
+}
+
+impl PagingPredicate {
+  pub fn new(page_size: NonZeroU32) -> Self {
+    Self {
+      predicate: None,
+      comparator: None,
+      page_size: i32::try_from(page_size.get()).unwrap_or(i32::MAX),
+      page: 0,
+      iteration_type: IterationType::Entry,
+      anchor_list: vec![],
+    }
+  }
+
+  pub fn with_predicate(mut self, predicate: Predicate) -> Self {
+    self.predicate = Some(predicate);
+    self
+  }
+
+  pub fn with_comparator(mut self, comparator: ServerSideFunction) -> Self {
+    self.comparator = Some(comparator);
+    self
+  }
+
+  pub fn get_page_size(&self) -> i32 {
+    self.page_size
+  }
+
+  pub fn get_page(&self) -> i32 {
+    self.page
+  }
+
+  pub fn set_page(&mut self, page: i32) {
+    self.page = page;
+  }
+
+  pub fn next_page(&mut self) {
+    self.page += 1;
+  }
+
+  pub fn previous_page(&mut self) {
+    if self.page > 0 {
+      self.page -= 1;
+    }
+  }
+
+  pub fn reset(&mut self) {
+    self.page = 0;
+    self.anchor_list.clear();
+  }
+
+  pub fn get_iteration_type(&self) -> IterationType {
+    self.iteration_type
+  }
+
+  pub fn set_iteration_type(&mut self, iteration_type: IterationType) {
+    self.iteration_type = iteration_type;
+  }
+
+  pub fn get_anchor_list(&self) -> &Vec<(i32, (HeapData, HeapData))> {
+    &self.anchor_list
+  }
+
+  pub fn set_anchor_list(&mut self, anchor_data_list_holder: AnchorDataListHolder) {
+    self.anchor_list = anchor_data_list_holder.anchor_page_list
+      .into_iter()
+      .zip(anchor_data_list_holder.anchor_data_list)
+      .collect();
+  }
+
+  pub fn to_holder(&self, serialization_service: &Arc<SerializationServiceV1>) -> PagingPredicateHolder {
+    let (anchor_page_list, anchor_data_list) = self.anchor_list.iter().cloned().unzip();
+    PagingPredicateHolder::new(
+      AnchorDataListHolder::new(anchor_page_list, anchor_data_list),
+      self.predicate.clone().map(|predicate| serialization_service.to_data(Box::new(predicate))),
+      self.comparator.as_ref().map(|comparator| comparator.to_data(serialization_service)),
+      self.page_size,
+      self.page,
+      self.iteration_type.id(),
+      None,
+    )
+  }
+}
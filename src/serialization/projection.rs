@@ -0,0 +1,108 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use crate::serialization::data::{DataInput, DataOutput};
+use crate::serialization::data::object_data_input::ObjectDataInput;
+use crate::serialization::data::object_data_output::ObjectDataOutput;
+use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableInfo, IdentifiedDataSerializableSerialization, IdentifiedWrappedSerializer, Serializable};
+use crate::serialization::serializer::Serializer;
+use crate::serialization::service::SerializationServiceV1;
+
+pub const PROJECTION_FACTORY_ID: i32 = -30;
+
+pub const SINGLE_ATTRIBUTE_PROJECTION_CLASS_ID: i32 = 0;
+pub const MULTI_ATTRIBUTE_PROJECTION_CLASS_ID: i32 = 1;
+
+/// Returned by [`Projection::multi_attribute`] if no attribute path is given.
+#[derive(Debug, Clone)]
+pub struct MissingAttributePathError;
+
+impl Display for MissingAttributePathError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "at least one attribute path must be given")
+  }
+}
+
+impl std::error::Error for MissingAttributePathError {}
+
+/// Extracts attributes from the map values on the members. A single
+/// attribute projection yields the attribute itself, a multi attribute
+/// projection yields one `Vec<Value>` per entry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Projection {
+  SingleAttribute { attribute_path: String },
+  MultiAttribute { attribute_paths: Vec<String> },
+  /// A projection the client has no counterpart for. Its fields are skipped.
+  Unknown { class_id: i32 },
+}
+
+impl Projection {
+  pub fn single_attribute(attribute_path: impl ToString) -> Self {
+    Projection::SingleAttribute { attribute_path: attribute_path.to_string() }
+  }
+
+  pub fn multi_attribute(attribute_paths: impl IntoIterator<Item=impl ToString>) -> Result<Self, MissingAttributePathError> {
+    let attribute_paths: Vec<String> = attribute_paths.into_iter().map(|path| path.to_string()).collect();
+    if attribute_paths.is_empty() {
+      return Err(MissingAttributePathError);
+    }
+    Ok(Projection::MultiAttribute { attribute_paths })
+  }
+
+  fn empty(class_id: i32) -> Self {
+    match class_id {
+      SINGLE_ATTRIBUTE_PROJECTION_CLASS_ID => Projection::SingleAttribute { attribute_path: String::new() },
+      MULTI_ATTRIBUTE_PROJECTION_CLASS_ID => Projection::MultiAttribute { attribute_paths: vec![] },
+      class_id => Projection::Unknown { class_id },
+    }
+  }
+}
+
+impl IdentifiedDataSerializableInfo for Projection {
+  fn factory_id(&self) -> i32 {
+    PROJECTION_FACTORY_ID
+  }
+
+  fn class_id(&self) -> i32 {
+    match self {
+      Projection::SingleAttribute { .. } => SINGLE_ATTRIBUTE_PROJECTION_CLASS_ID,
+      Projection::MultiAttribute { .. } => MULTI_ATTRIBUTE_PROJECTION_CLASS_ID,
+      Projection::Unknown { class_id } => *class_id,
+    }
+  }
+}
+
+impl IdentifiedDataSerializableSerialization for Projection {
+  fn read_data(&mut self, input: &mut ObjectDataInput) {
+    match self {
+      Projection::SingleAttribute { attribute_path } => {
+        *attribute_path = input.read_string().unwrap_or_default();
+      }
+      Projection::MultiAttribute { attribute_paths } => {
+        *attribute_paths = input.read_string_array().unwrap_or_default();
+      }
+      Projection::Unknown { .. } => {}
+    }
+  }
+
+  fn write_data(&mut self, output: &mut ObjectDataOutput) {
+    match self {
+      Projection::SingleAttribute { attribute_path } => {
+        output.write_string(Some(attribute_path));
+      }
+      Projection::MultiAttribute { attribute_paths } => {
+        output.write_string_array(Some(attribute_paths));
+      }
+      Projection::Unknown { .. } => {}
+    }
+  }
+}
+
+impl Serializable for Projection {
+  fn get_serializer(&self, service: &SerializationServiceV1) -> Arc<dyn Serializer<Box<Self>>> {
+    Arc::new(IdentifiedWrappedSerializer::new(service.identified_serializer.clone()))
+  }
+}
+
+pub fn projection_factory(class_id: i32) -> Box<dyn IdentifiedDataSerializable> {
+  Box::new(Projection::empty(class_id))
+}
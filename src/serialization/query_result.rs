@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use num_bigint::BigInt;
+use crate::core::big_decimal::BigDecimal;
+use crate::serialization::aggregation::aggregator::CanonicalizingSet;
+use crate::serialization::heap_data::HeapData;
+use crate::serialization::serializer::Serializer;
+use crate::serialization::service::SerializationServiceV1;
+use crate::serialization::value::Value;
+
+/// Returned when the member answered with a value that does not fit the
+/// result type the caller asked for.
+#[derive(Debug, Clone)]
+pub struct QueryResultTypeError {
+  pub value: Value,
+  pub expected: &'static str,
+}
+
+impl Display for QueryResultTypeError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "cannot convert {:?} into {}", self.value, self.expected)
+  }
+}
+
+impl std::error::Error for QueryResultTypeError {}
+
+/// Converts an aggregation or projection result sent by the member into the
+/// type the caller asked for. Numbers are widened like Java widens them, but
+/// never narrowed.
+pub trait QueryResult: Sized + Send + Sync + 'static {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError>;
+
+  fn from_data(serialization_service: &Arc<SerializationServiceV1>, data: Option<HeapData>) -> Result<Self, QueryResultTypeError> {
+    Self::from_value(data.map(|data| serialization_service.to_value(data)).unwrap_or(Value::Null))
+  }
+}
+
+fn type_error<T>(value: Value, expected: &'static str) -> Result<T, QueryResultTypeError> {
+  Err(QueryResultTypeError { value, expected })
+}
+
+impl QueryResult for Value {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    Ok(value)
+  }
+}
+
+impl QueryResult for i32 {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    match value {
+      Value::Byte(value) => Ok(value as i32),
+      Value::Short(value) => Ok(value as i32),
+      Value::Integer(value) => Ok(value),
+      Value::Null => Ok(0),
+      value => type_error(value, "i32"),
+    }
+  }
+}
+
+impl QueryResult for i64 {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    match value {
+      Value::Byte(value) => Ok(value as i64),
+      Value::Short(value) => Ok(value as i64),
+      Value::Integer(value) => Ok(value as i64),
+      Value::Long(value) => Ok(value),
+      Value::Null => Ok(0),
+      value => type_error(value, "i64"),
+    }
+  }
+}
+
+impl QueryResult for f64 {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    match value {
+      Value::Float(value) => Ok(value as f64),
+      Value::Double(value) => Ok(value),
+      Value::Null => Ok(0.0),
+      value => i64::from_value(value).map(|value| value as f64).map_err(|error| QueryResultTypeError { expected: "f64", ..error }),
+    }
+  }
+}
+
+impl QueryResult for String {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    match value {
+      Value::String(value) => Ok(value),
+      Value::Char(value) => Ok(value.to_string()),
+      value => type_error(value, "String"),
+    }
+  }
+}
+
+impl QueryResult for BigInt {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    match value {
+      Value::BigInteger(value) => Ok(value),
+      value => i64::from_value(value).map(BigInt::from).map_err(|error| QueryResultTypeError { expected: "BigInt", ..error }),
+    }
+  }
+}
+
+impl QueryResult for BigDecimal {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    match value {
+      Value::BigDecimal(value) => Ok(value),
+      value => BigInt::from_value(value).map(|value| BigDecimal::new(value, 0)).map_err(|error| QueryResultTypeError { expected: "BigDecimal", ..error }),
+    }
+  }
+}
+
+impl<T: QueryResult> QueryResult for Option<T> {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    match value {
+      Value::Null => Ok(None),
+      value => T::from_value(value).map(Some),
+    }
+  }
+}
+
+impl QueryResult for Vec<Value> {
+  fn from_value(value: Value) -> Result<Self, QueryResultTypeError> {
+    match value {
+      Value::Null => Ok(vec![]),
+      value => Ok(vec![value]),
+    }
+  }
+
+  fn from_data(serialization_service: &Arc<SerializationServiceV1>, data: Option<HeapData>) -> Result<Self, QueryResultTypeError> {
+    match data {
+      Some(data) if data.get_type() == serialization_service.identified_serializer.id() => {
+        Ok(serialization_service.to_identified::<CanonicalizingSet>(data).values)
+      }
+      Some(data) => Ok(serialization_service.to_value_list(data)),
+      None => Ok(vec![]),
+    }
+  }
+}
@@ -1,8 +1,10 @@
 use std::any::Any;
+use std::marker::PhantomData;
 use std::sync::Arc;
 use crate::connection::address::Address;
 use crate::serialization::data::object_data_input::ObjectDataInput;
 use crate::serialization::data::object_data_output::ObjectDataOutput;
+use crate::serialization::default_serializer::IdentifiedDataSerializableSerializer;
 use crate::serialization::serializer::Serializer;
 use crate::serialization::service::SerializationServiceV1;
 
@@ -82,6 +84,35 @@ impl<T> Serializer<Box<T>> for OptionWrappedSerializer<T> where T: 'static {
   }
 }
 
+pub struct IdentifiedWrappedSerializer<T> {
+  serializer: Arc<IdentifiedDataSerializableSerializer>,
+  phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> IdentifiedWrappedSerializer<T> {
+  pub fn new(serializer: Arc<IdentifiedDataSerializableSerializer>) -> Self {
+    Self {
+      serializer,
+      phantom: PhantomData::default(),
+    }
+  }
+}
+
+impl<T> Serializer<Box<T>> for IdentifiedWrappedSerializer<T> where T: IdentifiedDataSerializable {
+  fn id(&self) -> i32 {
+    self.serializer.id()
+  }
+
+  fn read(&self, input: &mut ObjectDataInput) -> Box<T> {
+    let object: Box<dyn Any> = self.serializer.read(input);
+    object.downcast().unwrap()
+  }
+
+  fn write(&self, output: &mut ObjectDataOutput, object: Box<T>) {
+    self.serializer.write(output, object)
+  }
+}
+
 //
 // pub trait IdentifiedDataSerializableRef<T>: AsMut<T> + AsRef<T> {}
 //
@@ -2,6 +2,7 @@ use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 
 use crate::config::serialization::{SerializationConfig};
 use crate::core::rest_value::{rest_value_factory, REST_VALUE_FACTORY_ID};
+use crate::proxy::event_journal::{map_data_factory, MAP_DATA_FACTORY_ID};
 use crate::proxy::topic::reliable_topic_message::{
   reliable_topic_message_factory, RELIABLE_TOPIC_MESSAGE_FACTORY_ID,
 };
@@ -16,8 +17,9 @@ use crate::serialization::default_predicates::{predicate_factory, PREDICATE_FACT
 use crate::serialization::default_serializer::IdentifiedDataSerializableSerializer;
 use crate::serialization::heap_data::HeapData;
 use crate::serialization::portable::serializer::PortableSerializer;
+use crate::serialization::projection::{projection_factory, PROJECTION_FACTORY_ID};
 use crate::serialization::schema_service::SchemaService;
-use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableSerialization, Serializable};
+use crate::serialization::serializable::{IdentifiedDataSerializable, IdentifiedDataSerializableInfo, IdentifiedDataSerializableSerialization, IdentifiedWrappedSerializer, Serializable};
 use crate::serialization::serializer::default::array_list_serializer::ArrayListSerializer;
 use crate::serialization::serializer::default::big_decimal_serializer::BigDecimalSerializer;
 use crate::serialization::serializer::default::big_int_serializer::BigIntSerializer;
@@ -50,6 +52,7 @@ use crate::serialization::serializer::default::string_array_serializer::StringAr
 use crate::serialization::serializer::default::string_serializer::StringSerializer;
 use crate::serialization::serializer::default::uuid_serializer::UuidSerializer;
 use crate::serialization::serializer::Serializer;
+use crate::serialization::value::Value;
 use crate::ClientConfig;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
@@ -184,7 +187,9 @@ impl SerializationServiceV1 {
     );
     factories.insert(CLUSTER_DATA_FACTORY_ID, Arc::new(cluster_data_factory));
     factories.insert(AGGREGATOR_FACTORY_ID, Arc::new(aggregator_factory));
+    factories.insert(PROJECTION_FACTORY_ID, Arc::new(projection_factory));
     factories.insert(REST_VALUE_FACTORY_ID, Arc::new(rest_value_factory));
+    factories.insert(MAP_DATA_FACTORY_ID, Arc::new(map_data_factory));
     IdentifiedDataSerializableSerializer::new(factories)
   }
 
@@ -227,8 +232,29 @@ impl SerializationServiceV1 {
     serializer.read(&mut data_input)
   }
 
-  pub fn write_object<T>(&self, out: &mut ObjectDataOutput, obj: &T) {
-    todo!()
+  pub fn to_value(self: &Arc<Self>, data: HeapData) -> Value {
+    let mut data_input = ObjectDataInput::new(data.to_buffer(), Self::DATA_OFFSET, self.clone(), self.serialization_config.is_big_endian);
+    Value::read_typed(data.get_type(), &mut data_input)
+  }
+
+  /// Reads a list the member serialized as a java array or collection.
+  pub fn to_value_list(self: &Arc<Self>, data: HeapData) -> Vec<Value> {
+    let mut data_input = ObjectDataInput::new(data.to_buffer(), Self::DATA_OFFSET, self.clone(), self.serialization_config.is_big_endian);
+    let size = data_input.read_int();
+    (0..size).map(|_| Value::read(&mut data_input)).collect()
+  }
+
+  pub fn to_identified<T: IdentifiedDataSerializable>(self: &Arc<Self>, data: HeapData) -> Box<T> {
+    let mut data_input = ObjectDataInput::new(data.to_buffer(), Self::DATA_OFFSET, self.clone(), self.serialization_config.is_big_endian);
+    IdentifiedWrappedSerializer::<T>::new(self.identified_serializer.clone()).read(&mut data_input)
+  }
+
+  pub fn write_object<T: IdentifiedDataSerializableSerialization + IdentifiedDataSerializableInfo>(&self, out: &mut ObjectDataOutput, obj: &mut T) {
+    out.write_int(self.identified_serializer.id());
+    out.write_boolean(true);
+    out.write_int(obj.factory_id());
+    out.write_int(obj.class_id());
+    obj.write_data(out);
   }
 }
 
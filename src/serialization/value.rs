@@ -0,0 +1,216 @@
+use std::any::Any;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use num_bigint::BigInt;
+use uuid::Uuid;
+use crate::core::big_decimal::BigDecimal;
+use crate::serialization::data::{DataInput, DataOutput};
+use crate::serialization::data::object_data_input::ObjectDataInput;
+use crate::serialization::data::object_data_output::ObjectDataOutput;
+use crate::serialization::serializer::default::big_decimal_serializer::BigDecimalSerializer;
+use crate::serialization::serializer::default::big_int_serializer::BigIntSerializer;
+use crate::serialization::serializer::default::boolean_serializer::BooleanSerializer;
+use crate::serialization::serializer::default::byte_serializer::ByteSerializer;
+use crate::serialization::serializer::default::char_serializer::CharSerializer;
+use crate::serialization::serializer::default::double_serializer::DoubleSerializer;
+use crate::serialization::serializer::default::float_serializer::FloatSerializer;
+use crate::serialization::serializer::default::integer_serializer::IntegerSerializer;
+use crate::serialization::serializer::default::local_date_serializer::LocalDateSerializer;
+use crate::serialization::serializer::default::local_date_time_serializer::LocalDateTimeSerializer;
+use crate::serialization::serializer::default::local_time_serializer::LocalTimeSerializer;
+use crate::serialization::serializer::default::long_serializer::LongSerializer;
+use crate::serialization::serializer::default::null_serializer::NullSerializer;
+use crate::serialization::serializer::default::offset_date_time_serializer::OffsetDateTimeSerializer;
+use crate::serialization::serializer::default::short_serializer::ShortSerializer;
+use crate::serialization::serializer::default::string_serializer::StringSerializer;
+use crate::serialization::serializer::default::uuid_serializer::UuidSerializer;
+use crate::serialization::serializer::Serializer;
+
+/// A dynamically typed value for places where the member decides the type,
+/// e.g. predicate operands, aggregation results and projected attributes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+  Null,
+  Boolean(bool),
+  Byte(u8),
+  Char(char),
+  Short(i16),
+  Integer(i32),
+  Long(i64),
+  Float(f32),
+  Double(f64),
+  String(String),
+  Uuid(Uuid),
+  BigInteger(BigInt),
+  BigDecimal(BigDecimal),
+  LocalDate(NaiveDate),
+  LocalTime(NaiveTime),
+  LocalDateTime(NaiveDateTime),
+  OffsetDateTime(DateTime<FixedOffset>),
+  /// A value of a type id the client cannot read. It is written as null.
+  Unknown(i32),
+}
+
+impl Value {
+  pub fn write(&self, output: &mut ObjectDataOutput) {
+    match self.clone() {
+      Value::Null | Value::Unknown(_) => output.write_int(NullSerializer::NULL_TYPE_ID),
+      Value::Boolean(value) => Self::write_with(output, &BooleanSerializer, value),
+      Value::Byte(value) => Self::write_with(output, &ByteSerializer, value),
+      Value::Char(value) => Self::write_with(output, &CharSerializer, value),
+      Value::Short(value) => Self::write_with(output, &ShortSerializer, value),
+      Value::Integer(value) => Self::write_with(output, &IntegerSerializer, value),
+      Value::Long(value) => Self::write_with(output, &LongSerializer, value),
+      Value::Float(value) => Self::write_with(output, &FloatSerializer, value),
+      Value::Double(value) => Self::write_with(output, &DoubleSerializer, value),
+      Value::String(value) => Self::write_with(output, &StringSerializer, Some(value)),
+      Value::Uuid(value) => Self::write_with(output, &UuidSerializer, value),
+      Value::BigInteger(value) => Self::write_with(output, &BigIntSerializer, value),
+      Value::BigDecimal(value) => Self::write_with(output, &BigDecimalSerializer, value),
+      Value::LocalDate(value) => Self::write_with(output, &LocalDateSerializer, value),
+      Value::LocalTime(value) => Self::write_with(output, &LocalTimeSerializer, value),
+      Value::LocalDateTime(value) => Self::write_with(output, &LocalDateTimeSerializer, value),
+      Value::OffsetDateTime(value) => Self::write_with(output, &OffsetDateTimeSerializer, value),
+    }
+  }
+
+  pub fn read(input: &mut ObjectDataInput) -> Self {
+    let type_id = input.read_int();
+    Self::read_typed(type_id, input)
+  }
+
+  pub fn read_typed(type_id: i32, input: &mut ObjectDataInput) -> Self {
+    match type_id {
+      NullSerializer::NULL_TYPE_ID => Value::Null,
+      id if id == BooleanSerializer.id() => Value::Boolean(*BooleanSerializer.read(input)),
+      id if id == ByteSerializer.id() => Value::Byte(*ByteSerializer.read(input)),
+      id if id == CharSerializer.id() => Value::Char(*CharSerializer.read(input)),
+      id if id == ShortSerializer.id() => Value::Short(*ShortSerializer.read(input)),
+      id if id == IntegerSerializer.id() => Value::Integer(*IntegerSerializer.read(input)),
+      id if id == LongSerializer.id() => Value::Long(*LongSerializer.read(input)),
+      id if id == FloatSerializer.id() => Value::Float(*FloatSerializer.read(input)),
+      id if id == DoubleSerializer.id() => Value::Double(*DoubleSerializer.read(input)),
+      id if id == StringSerializer.id() => StringSerializer.read(input).map(Value::String).unwrap_or(Value::Null),
+      id if id == UuidSerializer.id() => Value::Uuid(*UuidSerializer.read(input)),
+      id if id == BigIntSerializer.id() => Value::BigInteger(*BigIntSerializer.read(input)),
+      id if id == BigDecimalSerializer.id() => Value::BigDecimal(*BigDecimalSerializer.read(input)),
+      id if id == LocalDateSerializer.id() => Value::LocalDate(*LocalDateSerializer.read(input)),
+      id if id == LocalTimeSerializer.id() => Value::LocalTime(*LocalTimeSerializer.read(input)),
+      id if id == LocalDateTimeSerializer.id() => Value::LocalDateTime(*LocalDateTimeSerializer.read(input)),
+      id if id == OffsetDateTimeSerializer.id() => Value::OffsetDateTime(*OffsetDateTimeSerializer.read(input)),
+      id => Value::Unknown(id),
+    }
+  }
+
+  fn write_with<T: Any>(output: &mut ObjectDataOutput, serializer: &dyn Serializer<Box<T>>, value: T) {
+    output.write_int(serializer.id());
+    serializer.write(output, Box::new(value));
+  }
+}
+
+impl From<bool> for Value {
+  fn from(value: bool) -> Self {
+    Value::Boolean(value)
+  }
+}
+
+impl From<u8> for Value {
+  fn from(value: u8) -> Self {
+    Value::Byte(value)
+  }
+}
+
+impl From<char> for Value {
+  fn from(value: char) -> Self {
+    Value::Char(value)
+  }
+}
+
+impl From<i16> for Value {
+  fn from(value: i16) -> Self {
+    Value::Short(value)
+  }
+}
+
+impl From<i32> for Value {
+  fn from(value: i32) -> Self {
+    Value::Integer(value)
+  }
+}
+
+impl From<i64> for Value {
+  fn from(value: i64) -> Self {
+    Value::Long(value)
+  }
+}
+
+impl From<f32> for Value {
+  fn from(value: f32) -> Self {
+    Value::Float(value)
+  }
+}
+
+impl From<f64> for Value {
+  fn from(value: f64) -> Self {
+    Value::Double(value)
+  }
+}
+
+impl From<String> for Value {
+  fn from(value: String) -> Self {
+    Value::String(value)
+  }
+}
+
+impl From<&str> for Value {
+  fn from(value: &str) -> Self {
+    Value::String(value.to_string())
+  }
+}
+
+impl From<Uuid> for Value {
+  fn from(value: Uuid) -> Self {
+    Value::Uuid(value)
+  }
+}
+
+impl From<BigInt> for Value {
+  fn from(value: BigInt) -> Self {
+    Value::BigInteger(value)
+  }
+}
+
+impl From<BigDecimal> for Value {
+  fn from(value: BigDecimal) -> Self {
+    Value::BigDecimal(value)
+  }
+}
+
+impl From<NaiveDate> for Value {
+  fn from(value: NaiveDate) -> Self {
+    Value::LocalDate(value)
+  }
+}
+
+impl From<NaiveTime> for Value {
+  fn from(value: NaiveTime) -> Self {
+    Value::LocalTime(value)
+  }
+}
+
+impl From<NaiveDateTime> for Value {
+  fn from(value: NaiveDateTime) -> Self {
+    Value::LocalDateTime(value)
+  }
+}
+
+impl From<DateTime<FixedOffset>> for Value {
+  fn from(value: DateTime<FixedOffset>) -> Self {
+    Value::OffsetDateTime(value)
+  }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+  fn from(value: Option<T>) -> Self {
+    value.map(Into::into).unwrap_or(Value::Null)
+  }
+}
@@ -3,4 +3,5 @@ pub mod io_util;
 pub mod big_decimal_util;
 pub mod future;
 pub mod maybe_future;
-pub mod observable_weak_arc;
\ No newline at end of file
+pub mod observable_weak_arc;
+pub mod thread_id;
\ No newline at end of file
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+tokio::task_local! {
+  static THREAD_ID: i64;
+}
+
+static NEXT_THREAD_ID: AtomicI64 = AtomicI64::new(ThreadId::DEFAULT + 1);
+
+/// Returned by operations that acquire or release locks outside of a
+/// [`ThreadId::scope`]. Outside of a scope all tasks share
+/// `ThreadId::DEFAULT`, so they would own each other's locks.
+#[derive(Debug, Clone)]
+pub struct MissingThreadIdScopeError;
+
+impl Display for MissingThreadIdScopeError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "locks can only be used inside of ThreadId::scope")
+  }
+}
+
+impl std::error::Error for MissingThreadIdScopeError {}
+
+/// Client side stand-in for the thread id the protocol expects on key operations.
+/// Locks are owned by the thread id they were acquired with, so every task that
+/// locks runs inside its own [`ThreadId::scope`].
+pub struct ThreadId;
+
+impl ThreadId {
+  pub const DEFAULT: i64 = 1;
+
+  /// Id of the enclosing scope, `DEFAULT` outside of one. Good enough for
+  /// operations that only have to respect locks, not own them.
+  pub fn current() -> i64 {
+    Self::scoped().unwrap_or(Self::DEFAULT)
+  }
+
+  /// Id of the enclosing scope, for operations that own locks.
+  pub fn scoped() -> Result<i64, MissingThreadIdScopeError> {
+    THREAD_ID.try_with(|thread_id| *thread_id).map_err(|_| MissingThreadIdScopeError)
+  }
+
+  pub async fn scope<F: Future>(future: F) -> F::Output {
+    THREAD_ID.scope(NEXT_THREAD_ID.fetch_add(1, Ordering::SeqCst), future).await
+  }
+}